@@ -0,0 +1,284 @@
+// Explicit returns are the preferred style in this crate
+#![allow(clippy::needless_return)]
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+use advent_of_code_2016::day_05;
+use advent_of_code_2016::solution::{self, Puzzle};
+use advent_of_code_2016::verify::{self, VerifyStatus};
+
+const USAGE: &str = "\
+Usage:
+    aoc2016 run --day <N> [--part <P>] [--input <PATH>]
+    aoc2016 run --all [--input-dir <DIR>]
+    aoc2016 verify [--answers <PATH>]
+    aoc2016 cinematic [--input <PATH>]
+
+Options:
+    --day <N>          Day of the puzzle to solve
+    --part <P>         Part of the puzzle to solve (1 or 2) - both parts are solved if omitted
+    --input <PATH>     Puzzle input file, or \"-\" to read from stdin [default: input/2016/day<N>.txt]
+    --all              Solve every implemented day using the files in the input directory
+    --input-dir <DIR>  Directory containing the day<N>.txt input files [default: input/2016]
+    --answers <PATH>   Answers file listing input files and expected answers [default: input/2016/answers.json]
+
+The cinematic command decrypts the day 5 part 2 password with an animation when run in a terminal.";
+
+/// Default directory containing puzzle input files, relative to the crate root.
+const DEFAULT_INPUT_DIR: &str = "input/2016";
+
+/// Default answers file used in verify mode, relative to the crate root.
+const DEFAULT_ANSWERS_PATH: &str = "input/2016/answers.json";
+
+/// Represents the puzzles selected to be solved by the command-line arguments.
+enum RunTarget {
+    Single {
+        day: u32,
+        part: Option<u32>,
+        input: Option<String>,
+    },
+    All {
+        input_dir: String,
+    },
+    Verify {
+        answers_path: String,
+    },
+    Cinematic {
+        input: Option<String>,
+    },
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let target = match parse_args(&args) {
+        Ok(target) => target,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+    let registry = solution::registry();
+    let success = match target {
+        RunTarget::Single { day, part, input } => {
+            let puzzle = match registry.get(&day) {
+                Some(puzzle) => puzzle,
+                None => {
+                    eprintln!("error: day {} is not implemented", day);
+                    process::exit(2);
+                }
+            };
+            let input_path = input.unwrap_or_else(|| format!("{}/day{}.txt", DEFAULT_INPUT_DIR, day));
+            let parts = match part {
+                Some(part) => vec![part],
+                None => (1..=2).filter(|part| puzzle.has_part(*part)).collect::<Vec<u32>>(),
+            };
+            run_day(puzzle.as_ref(), &parts, &input_path)
+        }
+        RunTarget::All { input_dir } => {
+            let mut success = true;
+            for (day, puzzle) in registry.iter() {
+                let input_path = format!("{}/day{}.txt", input_dir, day);
+                let parts = (1..=2).filter(|part| puzzle.has_part(*part)).collect::<Vec<u32>>();
+                success &= run_day(puzzle.as_ref(), &parts, &input_path);
+            }
+            success
+        }
+        RunTarget::Verify { answers_path } => run_verify(&registry, &answers_path),
+        RunTarget::Cinematic { input } => {
+            let input_path = input.unwrap_or_else(|| format!("{}/day5.txt", DEFAULT_INPUT_DIR));
+            run_cinematic(&input_path)
+        }
+    };
+    if !success {
+        process::exit(1);
+    }
+}
+
+/// Parses the command-line arguments (excluding the program name) to determine what to run.
+fn parse_args(args: &[String]) -> Result<RunTarget, String> {
+    match args.first().map(|arg| arg.as_str()) {
+        Some("run") => (),
+        Some("verify") => return parse_verify_args(&args[1..]),
+        Some("cinematic") => return parse_cinematic_args(&args[1..]),
+        Some(command) => return Err(format!("unknown command \"{}\"", command)),
+        None => return Err(String::from("no command given")),
+    }
+    let mut day: Option<u32> = None;
+    let mut part: Option<u32> = None;
+    let mut input: Option<String> = None;
+    let mut input_dir: Option<String> = None;
+    let mut all = false;
+    let mut i = 1;
+    while i < args.len() {
+        let flag = args[i].as_str();
+        if flag == "--all" {
+            all = true;
+            i += 1;
+            continue;
+        }
+        let value = match args.get(i + 1) {
+            Some(value) => value.to_string(),
+            None => return Err(format!("missing value for \"{}\"", flag)),
+        };
+        match flag {
+            "--day" => day = Some(parse_number(flag, &value)?),
+            "--part" => part = Some(parse_number(flag, &value)?),
+            "--input" => input = Some(value),
+            "--input-dir" => input_dir = Some(value),
+            _ => return Err(format!("unknown option \"{}\"", flag)),
+        }
+        i += 2;
+    }
+    if all {
+        if day.is_some() || part.is_some() || input.is_some() {
+            return Err(String::from("--all cannot be combined with --day, --part or --input"));
+        }
+        return Ok(RunTarget::All {
+            input_dir: input_dir.unwrap_or_else(|| DEFAULT_INPUT_DIR.to_string()),
+        });
+    }
+    match day {
+        Some(day) => return Ok(RunTarget::Single { day, part, input }),
+        None => return Err(String::from("either --day or --all must be given")),
+    }
+}
+
+/// Parses the options for the verify command.
+fn parse_verify_args(args: &[String]) -> Result<RunTarget, String> {
+    match args {
+        [] => {
+            return Ok(RunTarget::Verify {
+                answers_path: DEFAULT_ANSWERS_PATH.to_string(),
+            });
+        }
+        [flag, value] if flag == "--answers" => {
+            return Ok(RunTarget::Verify {
+                answers_path: value.to_string(),
+            });
+        }
+        _ => return Err(String::from("verify accepts only \"--answers <PATH>\"")),
+    }
+}
+
+/// Parses the options for the cinematic command.
+fn parse_cinematic_args(args: &[String]) -> Result<RunTarget, String> {
+    match args {
+        [] => return Ok(RunTarget::Cinematic { input: None }),
+        [flag, value] if flag == "--input" => {
+            return Ok(RunTarget::Cinematic {
+                input: Some(value.to_string()),
+            });
+        }
+        _ => return Err(String::from("cinematic accepts only \"--input <PATH>\"")),
+    }
+}
+
+/// Parses the value of a numeric command-line option.
+fn parse_number(flag: &str, value: &str) -> Result<u32, String> {
+    return value.parse::<u32>().map_err(|_| format!("invalid value \"{}\" for \"{}\"", value, flag));
+}
+
+/// Reads the puzzle input from the given file path, or from stdin if the path is "-".
+fn read_input(input_path: &str) -> io::Result<String> {
+    if input_path == "-" {
+        let mut raw_input = String::new();
+        io::stdin().read_to_string(&mut raw_input)?;
+        return Ok(raw_input);
+    }
+    return fs::read_to_string(input_path);
+}
+
+/// Solves the given parts of a day's puzzle and prints the answers with timings. Returns false if
+/// the input could not be read or any part failed.
+fn run_day(puzzle: &dyn Puzzle, parts: &[u32], input_path: &str) -> bool {
+    let day = puzzle.get_day();
+    let raw_input = match read_input(input_path) {
+        Ok(raw_input) => raw_input,
+        Err(e) => {
+            eprintln!("Day {:02}: failed to read input \"{}\": {}", day, input_path, e);
+            return false;
+        }
+    };
+    let mut success = true;
+    for part in parts {
+        let start = Instant::now();
+        let result = puzzle.run_part(*part, &raw_input);
+        let duration = start.elapsed();
+        match result {
+            Ok(answer) => println!("Day {:02} - Part {}: {} [{:?}]", day, part, answer, duration),
+            Err(e) => {
+                eprintln!("Day {:02} - Part {}: error: {} [{:?}]", day, part, e, duration);
+                success = false;
+            }
+        }
+    }
+    return success;
+}
+
+/// Decrypts the day 5 part 2 password, animated if stdout is a terminal and with plain progress
+/// lines otherwise. Returns false if the input could not be read or the output could not be written.
+fn run_cinematic(input_path: &str) -> bool {
+    let door_id = match read_input(input_path) {
+        Ok(raw_input) => raw_input.trim().to_string(),
+        Err(e) => {
+            eprintln!("Day 05: failed to read input \"{}\": {}", input_path, e);
+            return false;
+        }
+    };
+    let stdout = io::stdout();
+    let animate = stdout.is_terminal();
+    match day_05::decrypt_cinematic(&door_id, &mut stdout.lock(), animate) {
+        Ok(_) => return true,
+        Err(e) => {
+            eprintln!("Day 05: failed to write output: {}", e);
+            return false;
+        }
+    }
+}
+
+/// Checks every registered day against the expected answers and prints a report. Returns false if
+/// the answers could not be loaded or any check failed.
+fn run_verify(registry: &BTreeMap<u32, Box<dyn Puzzle>>, answers_path: &str) -> bool {
+    let answers = match verify::load_answers(Path::new(answers_path)) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return false;
+        }
+    };
+    let outcomes = verify::verify_all(registry, &answers);
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for outcome in outcomes.iter() {
+        let input = match outcome.get_input_path() {
+            Some(path) => path.display().to_string(),
+            None => String::from("-"),
+        };
+        let label = format!("Day {:02} - Part {} [{}]", outcome.get_day(), outcome.get_part(), input);
+        match outcome.get_status() {
+            VerifyStatus::Pass => {
+                passed += 1;
+                println!("{}: PASS [{:?}]", label, outcome.get_duration());
+            }
+            VerifyStatus::Fail { expected, actual } => {
+                failed += 1;
+                println!("{}: FAIL - expected {:?}, got {:?} [{:?}]", label, expected, actual, outcome.get_duration());
+            }
+            VerifyStatus::Error(reason) => {
+                failed += 1;
+                println!("{}: ERROR - {} [{:?}]", label, reason, outcome.get_duration());
+            }
+            VerifyStatus::Missing(reason) => {
+                missing += 1;
+                println!("{}: MISSING - {}", label, reason);
+            }
+        }
+    }
+    println!("\n{} passed, {} failed, {} missing", passed, failed, missing);
+    return failed == 0;
+}
@@ -1,121 +1,706 @@
+use super::solution::Solution;
 use super::utils::carto::CardinalDirection;
+use super::utils::carto::Grid;
+use super::utils::carto::Metric;
 use super::utils::carto::Point2D;
+use super::utils::error::AocError;
 use regex::Regex;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 
-/// Represents the two possible turning directions - left or right.
-enum TurnDirection {
+/// Represents the verbs available in walking instructions. Each verb is followed by a step count.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum WalkVerb {
+    /// Turn 90 degrees to the left, then walk forward.
     Left,
+    /// Turn 90 degrees to the right, then walk forward.
     Right,
+    /// Walk forward without turning.
+    Forward,
+    /// Walk backward, keeping the current heading.
+    Backward,
+    /// Turn around to face the opposite way, then walk forward.
+    UTurn,
+    /// Turn to face the given absolute heading, then walk forward.
+    Face(CardinalDirection),
+}
+
+impl WalkVerb {
+    /// Determines the new heading and the direction of travel resulting from applying the verb
+    /// while facing the given heading.
+    fn apply(&self, heading: CardinalDirection) -> (CardinalDirection, CardinalDirection) {
+        match self {
+            WalkVerb::Left => return (heading.rotate_left(), heading.rotate_left()),
+            WalkVerb::Right => return (heading.rotate_right(), heading.rotate_right()),
+            WalkVerb::Forward => return (heading, heading),
+            WalkVerb::Backward => return (heading, heading.opposite()),
+            WalkVerb::UTurn => return (heading.opposite(), heading.opposite()),
+            WalkVerb::Face(dir) => return (*dir, *dir),
+        }
+    }
 }
 
 #[aoc_generator(day1)]
-fn generate_input(raw_input: &str) -> Vec<(TurnDirection, i64)> {
-    let mut input: Vec<(TurnDirection, i64)> = vec![];
-    let raw_input = raw_input.trim();
-    // Create regexes for matching instructions
-    let left_regex = Regex::new(r"^L(\d+)$").unwrap();
-    let right_regex = Regex::new(r"^R(\d+)$").unwrap();
-    for instruction in raw_input.split(", ") {
-        if left_regex.is_match(instruction) {
-            let captures = left_regex.captures(instruction).unwrap();
-            let steps = captures[1].parse::<i64>().unwrap();
-            input.push((TurnDirection::Left, steps));
-        } else if right_regex.is_match(instruction) {
-            let captures = right_regex.captures(instruction).unwrap();
-            let steps = captures[1].parse::<i64>().unwrap();
-            input.push((TurnDirection::Right, steps));
-        } else {
-            panic!("Day 1 - invalid instruction in input!");
+fn generate_input(raw_input: &str) -> Result<Vec<(WalkVerb, i64)>, AocError> {
+    let mut input: Vec<(WalkVerb, i64)> = vec![];
+    // Create regex for matching instructions, allowing whitespace between verb and step count
+    let instruction_regex = Regex::new(r"^([LRFBUNESW])\s*(\d+)$").unwrap();
+    // Bounding the total distance walked keeps every co-ordinate reached within range of an i64
+    let mut total_steps: i64 = 0;
+    for (line_index, line) in raw_input.lines().enumerate() {
+        // Track the byte offset of each instruction so errors can report its column
+        let mut part_offset = 0;
+        for part in line.split(',') {
+            let instruction = part.trim();
+            let offset = part_offset + part.len() - part.trim_start().len();
+            part_offset += part.len() + 1;
+            if instruction.is_empty() {
+                continue;
+            }
+            let captures = match instruction_regex.captures(instruction) {
+                Some(captures) => captures,
+                None => {
+                    return Err(AocError::parse_token(line_index, line, offset, instruction, "invalid instruction"));
+                }
+            };
+            let steps = match captures[2].parse::<i64>() {
+                Ok(steps) => steps,
+                Err(_) => {
+                    return Err(AocError::parse_token(line_index, line, offset, instruction, "step count out of range"));
+                }
+            };
+            total_steps = match total_steps.checked_add(steps) {
+                Some(total_steps) => total_steps,
+                None => {
+                    return Err(AocError::parse_token(line_index, line, offset, instruction, "total step count out of range"));
+                }
+            };
+            let verb = match &captures[1] {
+                "L" => WalkVerb::Left,
+                "R" => WalkVerb::Right,
+                "F" => WalkVerb::Forward,
+                "B" => WalkVerb::Backward,
+                "U" => WalkVerb::UTurn,
+                "N" => WalkVerb::Face(CardinalDirection::North),
+                "E" => WalkVerb::Face(CardinalDirection::East),
+                "S" => WalkVerb::Face(CardinalDirection::South),
+                _ => WalkVerb::Face(CardinalDirection::West),
+            };
+            input.push((verb, steps));
         }
     }
-    return input;
+    return Ok(input);
 }
 
-/// Updates the current location and direction based on the given turn direction and steps.
+/// Updates the current location and heading based on the given verb and steps.
 fn update_location(
     current_loc: &mut Point2D,
     current_dir: &mut CardinalDirection,
-    turn_dir: &TurnDirection,
+    verb: &WalkVerb,
     steps: &i64,
 ) {
-    // First conduct turn
-    match turn_dir {
-        TurnDirection::Left => *current_dir = current_dir.rotate_left(),
-        TurnDirection::Right => *current_dir = current_dir.rotate_right(),
-    }
+    // First apply any change of heading
+    let (heading, travel_dir) = verb.apply(*current_dir);
+    *current_dir = heading;
     // Then take the steps
-    match current_dir {
-        CardinalDirection::North => current_loc.move_point(0, -steps),
-        CardinalDirection::East => current_loc.move_point(*steps, 0),
-        CardinalDirection::South => current_loc.move_point(0, *steps),
-        CardinalDirection::West => current_loc.move_point(-steps, 0),
+    *current_loc += travel_dir.unit_vector() * *steps;
+}
+
+/// Maximum number of cells permitted when rendering a walk as ASCII art.
+const MAX_RENDER_CELLS: u64 = 10_000_000;
+
+/// Maximum number of steps permitted when tracing a walk, as every location visited is recorded.
+const MAX_TRACE_STEPS: u64 = 10_000_000;
+
+/// Represents the complete walk resulting from following the instructions from the origin.
+pub struct WalkTrace {
+    visited: Vec<Point2D>,
+    waypoints: Vec<Point2D>,
+    intersections: Vec<Point2D>
+}
+
+impl WalkTrace {
+    /// Gets every location on the walk in order, one per step, starting with the origin.
+    pub fn get_visited(&self) -> &Vec<Point2D> {
+        return &self.visited;
+    }
+
+    /// Gets the locations at the start of the walk and at the end of each instruction.
+    pub fn get_waypoints(&self) -> &Vec<Point2D> {
+        return &self.waypoints;
+    }
+
+    /// Gets every location where a step landed on an already-visited location, in the order the
+    /// revisits occurred. Locations visited more than twice are included once per revisit. As with
+    /// part 2, the origin is not considered visited until it is stepped onto.
+    pub fn get_intersections(&self) -> &Vec<Point2D> {
+        return &self.intersections;
+    }
+
+    /// Gets the first location visited twice, if any.
+    pub fn get_first_intersection(&self) -> Option<Point2D> {
+        return self.intersections.first().copied();
+    }
+
+    /// Gets the location at the end of the walk.
+    pub fn get_end(&self) -> Point2D {
+        return *self.visited.last().unwrap();
+    }
+
+    /// Gets the minimum and maximum corners of the smallest rectangle containing the whole walk.
+    pub fn get_bounding_box(&self) -> (Point2D, Point2D) {
+        // Waypoints include every extreme location, as the walk only changes direction at them
+        return Point2D::get_bounding_box(&self.waypoints).unwrap();
+    }
+
+    /// Renders the walk as ASCII art, with north at the top. Visited locations are shown as '#',
+    /// revisited locations as 'X', and the start and end locations as 'S' and 'E'.
+    pub fn render_ascii(&self) -> Result<String, AocError> {
+        let (min, max) = self.get_bounding_box();
+        let width = max.get_x().abs_diff(min.get_x()) + 1;
+        let height = max.get_y().abs_diff(min.get_y()) + 1;
+        if width.saturating_mul(height) > MAX_RENDER_CELLS {
+            return Err(AocError::InvalidParameter(format!(
+                "walk area of {}x{} is too large to render",
+                width, height
+            )));
+        }
+        let mut canvas = Grid::new(width as usize, height as usize, '.');
+        let offset = Point2D::new(0, 0) - min;
+        for loc in self.visited.iter() {
+            canvas.set(&(*loc + offset), '#');
+        }
+        for loc in self.intersections.iter() {
+            canvas.set(&(*loc + offset), 'X');
+        }
+        canvas.set(&(self.visited[0] + offset), 'S');
+        canvas.set(&(self.get_end() + offset), 'E');
+        return Ok(canvas.to_string());
+    }
+
+    /// Gets the SVG view box (minimum x, minimum y, width and height) covering the walk area plus a
+    /// margin of one. Returns None if any of the values does not fit in an i64.
+    fn get_svg_view_box(&self) -> Option<(i64, i64, i64, i64)> {
+        let (min, max) = self.get_bounding_box();
+        let view_x = min.get_x().checked_sub(1)?;
+        let view_y = min.get_y().checked_sub(1)?;
+        let view_width = max.get_x().checked_sub(min.get_x())?.checked_add(2)?;
+        let view_height = max.get_y().checked_sub(min.get_y())?.checked_add(2)?;
+        return Some((view_x, view_y, view_width, view_height));
+    }
+
+    /// Renders the walk as an SVG image, with north at the top. The route is drawn as a line with
+    /// revisited locations marked in red, the start in green and the end in blue. Returns an error if
+    /// the view box (the walk area plus a margin of one) cannot be represented.
+    pub fn render_svg(&self) -> Result<String, AocError> {
+        let (view_x, view_y, view_width, view_height) = match self.get_svg_view_box() {
+            Some(view_box) => view_box,
+            None => return Err(AocError::InvalidParameter(String::from("walk area is too large to render as SVG"))),
+        };
+        let mut svg = String::new();
+        svg.push_str(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n",
+            view_x, view_y, view_width, view_height
+        ));
+        let points = self
+            .waypoints
+            .iter()
+            .map(|p| format!("{},{}", p.get_x(), p.get_y()))
+            .collect::<Vec<String>>()
+            .join(" ");
+        svg.push_str(&format!(
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"black\" stroke-width=\"0.2\"/>\n",
+            points
+        ));
+        let markers = self
+            .intersections
+            .iter()
+            .map(|p| (p, "red"))
+            .chain(vec![(&self.visited[0], "green"), (self.visited.last().unwrap(), "blue")]);
+        for (loc, colour) in markers {
+            svg.push_str(&format!(
+                "  <circle cx=\"{}\" cy=\"{}\" r=\"0.4\" fill=\"{}\"/>\n",
+                loc.get_x(),
+                loc.get_y(),
+                colour
+            ));
+        }
+        svg.push_str("</svg>\n");
+        return Ok(svg);
+    }
+}
+
+/// Follows the instructions from the origin (facing north), recording every location visited.
+/// Returns an error if the walk has more than `MAX_TRACE_STEPS` steps in total.
+pub fn trace_walk(instructions: &[(WalkVerb, i64)]) -> Result<WalkTrace, AocError> {
+    let total_steps = instructions
+        .iter()
+        .fold(0u64, |total, (_, steps)| total.saturating_add(steps.unsigned_abs()));
+    if total_steps > MAX_TRACE_STEPS {
+        return Err(AocError::InvalidParameter(format!(
+            "walk of {} steps is too long to trace",
+            total_steps
+        )));
+    }
+    let start_loc = Point2D::new(0, 0);
+    let mut current_loc = start_loc;
+    let mut current_dir = CardinalDirection::North;
+    let mut visited = vec![start_loc];
+    let mut waypoints = vec![start_loc];
+    let mut intersections: Vec<Point2D> = vec![];
+    let mut visited_locs: HashSet<Point2D> = HashSet::new();
+    for (verb, steps) in instructions {
+        let (heading, travel_dir) = verb.apply(current_dir);
+        current_dir = heading;
+        let unit_vec = travel_dir.unit_vector();
+        // Conduct each step separately and check if each location has been seen
+        for _ in 0..*steps {
+            current_loc += unit_vec;
+            if !visited_locs.insert(current_loc) {
+                intersections.push(current_loc);
+            }
+            visited.push(current_loc);
+        }
+        waypoints.push(current_loc);
+    }
+    return Ok(WalkTrace {
+        visited: visited,
+        waypoints: waypoints,
+        intersections: intersections
+    });
+}
+
+/// Represents the available approaches for finding the first location visited twice.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RevisitStrategy {
+    /// Records every single step in a hash set. Memory use grows with the total steps walked.
+    StepSet,
+    /// Sweeps along the x-axis over the straight segments of the walk to find where they cross or
+    /// overlap. Takes O((n + k) log n) time and O(n) memory for n instructions and k crossing or
+    /// overlapping pairs of segments, regardless of step counts.
+    SegmentIntersection,
+}
+
+/// Represents the locations covered by one straight instruction of the walk - the points start +
+/// k * unit vector for k from 1 to steps (inclusive). The start location itself is not covered.
+struct WalkSegment {
+    start: Point2D,
+    dir: CardinalDirection,
+    steps: i64,
+    steps_before: u128
+}
+
+impl WalkSegment {
+    /// Checks if the segment runs along the x-axis.
+    fn is_horizontal(&self) -> bool {
+        return self.dir.unit_vector().get_dy() == 0;
+    }
+
+    /// Gets the co-ordinate that stays constant along the segment.
+    fn get_fixed_coord(&self) -> i64 {
+        if self.is_horizontal() {
+            return self.start.get_y();
+        }
+        return self.start.get_x();
+    }
+
+    /// Gets the co-ordinate that varies along the segment for the given location.
+    fn get_varying_coord(&self, loc: &Point2D) -> i64 {
+        if self.is_horizontal() {
+            return loc.get_x();
+        }
+        return loc.get_y();
+    }
+
+    /// Gets the inclusive range of the varying co-ordinate over the covered locations. Cannot
+    /// overflow, since the parser limits the total steps of the walk to the range of an i64.
+    fn get_covered_range(&self) -> (i64, i64) {
+        let origin = self.get_varying_coord(&self.start);
+        let sign = self.get_varying_coord(&(self.start + self.dir.unit_vector())) - origin;
+        if sign > 0 {
+            return (origin + 1, origin + self.steps);
+        }
+        return (origin - self.steps, origin - 1);
+    }
+
+    /// Finds the smallest number of steps along the current segment that lands on a location
+    /// covered by the other segment, if any.
+    fn find_first_overlap(&self, other: &WalkSegment) -> Option<i64> {
+        let (lo, hi) = self.get_covered_range();
+        let origin = self.get_varying_coord(&self.start);
+        let forward = hi > origin;
+        let steps_to = |coord: i64| (coord - origin).abs();
+        if self.is_horizontal() == other.is_horizontal() {
+            // Parallel segments only overlap if collinear, then the first overlap is at the
+            // near end of the shared range
+            if self.get_fixed_coord() != other.get_fixed_coord() {
+                return None;
+            }
+            let (other_lo, other_hi) = other.get_covered_range();
+            let (shared_lo, shared_hi) = (lo.max(other_lo), hi.min(other_hi));
+            if shared_lo > shared_hi {
+                return None;
+            }
+            return Some(steps_to(if forward { shared_lo } else { shared_hi }));
+        }
+        // Perpendicular segments cross at most once
+        let (other_lo, other_hi) = other.get_covered_range();
+        let crossing = other.get_fixed_coord();
+        let fixed = self.get_fixed_coord();
+        if crossing < lo || crossing > hi || fixed < other_lo || fixed > other_hi {
+            return None;
+        }
+        return Some(steps_to(crossing));
     }
 }
 
+/// Finds the first location visited twice when following the instructions from the origin, using
+/// the given strategy. As with part 2, the origin is not considered visited until stepped onto.
+pub fn find_first_revisit(instructions: &[(WalkVerb, i64)], strategy: RevisitStrategy) -> Option<Point2D> {
+    match strategy {
+        RevisitStrategy::StepSet => return find_first_revisit_by_steps(instructions),
+        RevisitStrategy::SegmentIntersection => return find_first_revisit_by_segments(instructions),
+    }
+}
+
+/// Finds the first revisited location by recording every step taken in a hash set.
+fn find_first_revisit_by_steps(instructions: &[(WalkVerb, i64)]) -> Option<Point2D> {
+    let mut current_loc = Point2D::new(0, 0);
+    let mut current_dir = CardinalDirection::North;
+    let mut visited_locs: HashSet<Point2D> = HashSet::new();
+    for (verb, steps) in instructions {
+        let (heading, travel_dir) = verb.apply(current_dir);
+        current_dir = heading;
+        let unit_vec = travel_dir.unit_vector();
+        for _ in 0..*steps {
+            current_loc += unit_vec;
+            if !visited_locs.insert(current_loc) {
+                return Some(current_loc);
+            }
+        }
+    }
+    return None;
+}
+
+/// Finds the first revisited location with a sweep over the segments of the walk. Vertical segments
+/// are checked for crossings against the horizontal segments active at their x-coordinate, and
+/// collinear segments are checked for overlaps in order of their covered ranges. The revisit is
+/// the overlap reached after the fewest total steps.
+fn find_first_revisit_by_segments(instructions: &[(WalkVerb, i64)]) -> Option<Point2D> {
+    // Lay out the segments, ignoring instructions that do not move
+    let mut segments: Vec<WalkSegment> = vec![];
+    let mut current_loc = Point2D::new(0, 0);
+    let mut current_dir = CardinalDirection::North;
+    let mut steps_before: u128 = 0;
+    for (verb, steps) in instructions {
+        let (heading, travel_dir) = verb.apply(current_dir);
+        current_dir = heading;
+        if *steps <= 0 {
+            continue;
+        }
+        segments.push(WalkSegment {
+            start: current_loc,
+            dir: travel_dir,
+            steps: *steps,
+            steps_before: steps_before
+        });
+        current_loc += travel_dir.unit_vector() * *steps;
+        steps_before += *steps as u128;
+    }
+    // Tracks the earliest revisit found as (total steps walked, segment index, steps along segment)
+    let mut first_revisit: Option<(u128, usize, i64)> = None;
+    let mut check_pair = |a: usize, b: usize| {
+        let (earlier, later) = (a.min(b), a.max(b));
+        if let Some(steps) = segments[later].find_first_overlap(&segments[earlier]) {
+            let total = segments[later].steps_before + steps as u128;
+            if first_revisit.is_none_or(|(best, _, _)| total < best) {
+                first_revisit = Some((total, later, steps));
+            }
+        }
+    };
+    // Sweep along the x-axis - horizontal segments become active over their covered x-range (events
+    // at the same x are ordered so insertions come before queries, then removals)
+    let mut events: Vec<(i64, u8, usize)> = vec![];
+    for (index, segment) in segments.iter().enumerate() {
+        let (lo, hi) = segment.get_covered_range();
+        if segment.is_horizontal() {
+            events.push((lo, 0, index));
+            events.push((hi, 2, index));
+        } else {
+            events.push((segment.get_fixed_coord(), 1, index));
+        }
+    }
+    events.sort_unstable();
+    let mut active: BTreeSet<(i64, usize)> = BTreeSet::new();
+    for (_, kind, index) in events {
+        let segment = &segments[index];
+        match kind {
+            0 => {
+                active.insert((segment.get_fixed_coord(), index));
+            }
+            1 => {
+                let (lo, hi) = segment.get_covered_range();
+                for (_, other) in active.range((lo, 0)..=(hi, usize::MAX)) {
+                    check_pair(*other, index);
+                }
+            }
+            _ => {
+                active.remove(&(segment.get_fixed_coord(), index));
+            }
+        }
+    }
+    // Collinear segments are sorted by their covered ranges, so each only needs checking against
+    // the segments on the same line whose ranges have not yet ended
+    let mut collinear = (0..segments.len()).collect::<Vec<usize>>();
+    let line_key = |index: &usize| {
+        let segment = &segments[*index];
+        return (segment.is_horizontal(), segment.get_fixed_coord(), segment.get_covered_range());
+    };
+    collinear.sort_unstable_by_key(line_key);
+    let mut line_active: Vec<usize> = vec![];
+    for index in collinear {
+        let (horizontal, fixed, (lo, _)) = line_key(&index);
+        line_active.retain(|other| {
+            let (other_horizontal, other_fixed, (_, other_hi)) = line_key(other);
+            return other_horizontal == horizontal && other_fixed == fixed && other_hi >= lo;
+        });
+        for other in line_active.iter() {
+            check_pair(*other, index);
+        }
+        line_active.push(index);
+    }
+    let (_, index, steps) = first_revisit?;
+    let segment = &segments[index];
+    return Some(segment.start + segment.dir.unit_vector() * steps);
+}
+
 #[aoc(day1, part1)]
-fn solve_part_1(input: &Vec<(TurnDirection, i64)>) -> u64 {
+fn solve_part_1(input: &Vec<(WalkVerb, i64)>) -> u64 {
     let start_loc = Point2D::new(0, 0);
     let mut current_loc = start_loc;
     let mut current_dir = CardinalDirection::North;
-    for (turn_dir, steps) in input {
+    for (verb, steps) in input {
         // Execute instruction to update location
-        update_location(&mut current_loc, &mut current_dir, turn_dir, steps)
+        update_location(&mut current_loc, &mut current_dir, verb, steps)
     }
     // Calculate distance from origin
     return current_loc.calculate_manhattan_distance(&start_loc);
 }
 
 #[aoc(day1, part2)]
-fn solve_part_2(input: &Vec<(TurnDirection, i64)>) -> u64 {
+fn solve_part_2(input: &Vec<(WalkVerb, i64)>) -> Result<u64, AocError> {
     let start_loc = Point2D::new(0, 0);
-    let mut current_loc = start_loc;
-    let mut current_dir = CardinalDirection::North;
-    let mut visited_locs: HashSet<Point2D> = HashSet::new();
-    // Process each instruction until a location is visited twice
-    for (turn_dir, steps) in input {
-        // First conduct turn
-        match turn_dir {
-            TurnDirection::Left => current_dir = current_dir.rotate_left(),
-            TurnDirection::Right => current_dir = current_dir.rotate_right(),
-        }
-        // Determine unit vector for updating location
-        let unit_vec = match current_dir {
-            CardinalDirection::North => (0, -1),
-            CardinalDirection::East => (1, 0),
-            CardinalDirection::South => (0, 1),
-            CardinalDirection::West => (-1, 0),
-        };
-        // Conduct each step separately and check if each location has been seen
-        for _ in 0..*steps {
-            current_loc.move_point(unit_vec.0, unit_vec.1);
-            if visited_locs.contains(&current_loc) {
-                return current_loc.calculate_manhattan_distance(&start_loc);
-            }
-            // Record each new location
-            visited_locs.insert(current_loc);
-        }
+    match find_first_revisit(input, RevisitStrategy::SegmentIntersection) {
+        Some(loc) => return Ok(loc.calculate_manhattan_distance(&start_loc)),
+        // Should have revisited a point - so getting here means the input has no solution
+        None => return Err(AocError::Unsolvable(String::from("did not visit a point twice"))),
+    }
+}
+
+/// Solution for AOC 2016 Day 1, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(WalkVerb, i64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn get_day(&self) -> u32 {
+        return 1;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return generate_input(raw_input);
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return solve_part_2(input);
     }
-    // Should have revisited a point - so getting here is an error!
-    panic!("D1_P2: did not visit a point twice!");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs::*;
+    use crate::utils::rng::XorShift;
 
     #[test]
     fn test_d01_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day1.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day1.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(332, result);
     }
 
     #[test]
     fn test_d01_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day1.txt").unwrap());
-        let result = solve_part_2(&input);
+        let input = generate_input(&read_to_string("./input/2016/day1.txt").unwrap()).unwrap();
+        let result = solve_part_2(&input).unwrap();
         assert_eq!(166, result);
     }
+
+    #[test]
+    fn test_d01_invalid_input() {
+        let error = generate_input("R2, L3,\nR5, X4, L1").err().unwrap();
+        assert_eq!(
+            AocError::Parse {
+                line: 2,
+                column: 5,
+                text: String::from("X4"),
+                reason: String::from("invalid instruction"),
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn test_d01_step_count_overflow() {
+        let error = generate_input("R9223372036854775807, L1, R1").err().unwrap();
+        assert_eq!(
+            AocError::Parse {
+                line: 1,
+                column: 23,
+                text: String::from("L1"),
+                reason: String::from("total step count out of range"),
+            },
+            error
+        );
+        let input = generate_input("R9223372036854775806, L1").unwrap();
+        assert_eq!(i64::MAX as u64, solve_part_1(&input));
+        assert!(find_first_revisit(&input, RevisitStrategy::SegmentIntersection).is_none());
+    }
+
+    #[test]
+    fn test_d01_extended_verbs() {
+        let input = generate_input("F3, R 2\n\tB1 ,U4\nN2, W 1,\n\nE1, S5, L2").unwrap();
+        assert_eq!(
+            vec![
+                (WalkVerb::Forward, 3),
+                (WalkVerb::Right, 2),
+                (WalkVerb::Backward, 1),
+                (WalkVerb::UTurn, 4),
+                (WalkVerb::Face(CardinalDirection::North), 2),
+                (WalkVerb::Face(CardinalDirection::West), 1),
+                (WalkVerb::Face(CardinalDirection::East), 1),
+                (WalkVerb::Face(CardinalDirection::South), 5),
+                (WalkVerb::Left, 2),
+            ],
+            input
+        );
+        // Backward keeps facing east, so the U-turn then heads west
+        let trace = trace_walk(&input[..4]).unwrap();
+        assert_eq!(Point2D::new(-3, -3), trace.get_end());
+        assert_eq!(Some(Point2D::new(1, -3)), trace.get_first_intersection());
+        assert_eq!(1, solve_part_1(&input));
+    }
+
+    #[test]
+    fn test_d01_trace_walk() {
+        let input = generate_input("R8, R4, R4, R8, R1, R5").unwrap();
+        let trace = trace_walk(&input).unwrap();
+        assert_eq!(31, trace.get_visited().len());
+        assert_eq!(Point2D::new(5, 1), trace.get_end());
+        assert_eq!(
+            &vec![Point2D::new(4, 0), Point2D::new(5, 0)],
+            trace.get_intersections()
+        );
+        assert_eq!((Point2D::new(0, -4), Point2D::new(8, 4)), trace.get_bounding_box());
+    }
+
+    #[test]
+    fn test_d01_render_walk() {
+        let input = generate_input("R2, L2, L1, L3").unwrap();
+        let trace = trace_walk(&input).unwrap();
+        assert_eq!(Some(Point2D::new(1, 0)), trace.get_first_intersection());
+        assert_eq!(".##\n.##\nSX#\n.E.\n", trace.render_ascii().unwrap());
+        let svg = trace.render_svg().unwrap();
+        assert!(svg.contains("points=\"0,0 2,0 2,-2 1,-2 1,1\""));
+        assert!(svg.contains("<circle cx=\"1\" cy=\"0\" r=\"0.4\" fill=\"red\"/>"));
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-1 -3 4 5\">"));
+    }
+
+    #[test]
+    fn test_d01_large_walks() {
+        let input = generate_input("R10000000, L1").unwrap();
+        assert!(matches!(trace_walk(&input), Err(AocError::InvalidParameter(_))));
+        // Walks too large to trace are built directly to check the SVG view box arithmetic
+        let corners = vec![Point2D::new(i64::MIN + 1, 0), Point2D::new(i64::MAX, 0)];
+        let trace = WalkTrace {
+            visited: corners.clone(),
+            waypoints: corners,
+            intersections: vec![]
+        };
+        assert!(matches!(trace.render_svg(), Err(AocError::InvalidParameter(_))));
+        assert!(matches!(trace.render_ascii(), Err(AocError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_d01_revisit_strategies_proper() {
+        let input = generate_input(&read_to_string("./input/2016/day1.txt").unwrap()).unwrap();
+        let by_steps = find_first_revisit(&input, RevisitStrategy::StepSet);
+        let by_segments = find_first_revisit(&input, RevisitStrategy::SegmentIntersection);
+        assert!(by_steps.is_some());
+        assert_eq!(by_steps, by_segments);
+        assert_eq!(by_steps, trace_walk(&input).unwrap().get_first_intersection());
+    }
+
+    #[test]
+    fn test_d01_revisit_strategies_random_walks() {
+        // Pseudo-random walks using every verb with short steps, so revisits (including collinear
+        // overlaps and immediate reversals) are common
+        let verbs = [
+            WalkVerb::Left,
+            WalkVerb::Right,
+            WalkVerb::Forward,
+            WalkVerb::Backward,
+            WalkVerb::UTurn,
+            WalkVerb::Face(CardinalDirection::North),
+            WalkVerb::Face(CardinalDirection::West),
+        ];
+        let mut rng = XorShift::new(0x2016);
+        // Longer walks that only turn left or right take many segments to cross themselves, so the
+        // sweep handles plenty of active segments before finding the revisit
+        for (verb_count, walk_len, max_steps) in [(verbs.len(), 20, 6), (2, 200, 50)] {
+            for _ in 0..500 {
+                let mut input: Vec<(WalkVerb, i64)> = vec![];
+                for _ in 0..walk_len {
+                    let verb = verbs[rng.next_below(verb_count as u64) as usize];
+                    input.push((verb, rng.next_below(max_steps) as i64));
+                }
+                assert_eq!(
+                    find_first_revisit(&input, RevisitStrategy::StepSet),
+                    find_first_revisit(&input, RevisitStrategy::SegmentIntersection)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_d01_segment_revisit_spiral() {
+        // An outward spiral never crosses itself, until the walk turns back inside its last turn
+        let mut input = (1..=1000).map(|steps| (WalkVerb::Right, steps)).collect::<Vec<(WalkVerb, i64)>>();
+        assert_eq!(None, find_first_revisit(&input, RevisitStrategy::SegmentIntersection));
+        input.push((WalkVerb::Right, 1));
+        input.push((WalkVerb::Right, 3000));
+        let by_segments = find_first_revisit(&input, RevisitStrategy::SegmentIntersection);
+        assert!(by_segments.is_some());
+        assert_eq!(find_first_revisit(&input, RevisitStrategy::StepSet), by_segments);
+    }
+
+    #[test]
+    fn test_d01_segment_revisit_large_steps() {
+        let input = generate_input("R5000000000, R3, R7, R9, R1000000000000").unwrap();
+        assert_eq!(
+            Some(Point2D::new(4999999993, 0)),
+            find_first_revisit(&input, RevisitStrategy::SegmentIntersection)
+        );
+    }
+
+    #[test]
+    fn test_d01_p2_no_revisit() {
+        let input = generate_input("R2, L3, R5").unwrap();
+        assert!(matches!(solve_part_2(&input), Err(AocError::Unsolvable(_))));
+    }
 }
@@ -1,133 +1,455 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+use super::utils::carto::Grid;
 use super::utils::carto::Point2D;
-
-/// Represents the possible movement directions used to determine keypad presses.
-enum MoveDir {
-    Up,
-    Down,
-    Left,
-    Right
-}
-
-impl MoveDir {
-    /// Determines the unit vector corresponding to the movement direction.
-    fn get_unit_vector(&self) -> (i64, i64) {
-        match self {
-            MoveDir::Up => (0, -1),
-            MoveDir::Down => (0, 1),
-            MoveDir::Left => (-1, 0),
-            MoveDir::Right => (1, 0)
-        }
-    }
-}
+use super::utils::carto::CardinalDirection;
+use super::utils::error::AocError;
+use super::utils::search::{breadth_first_search, find_reachable_within};
+use super::solution::Solution;
 
 #[aoc_generator(day2)]
-fn generate_input(raw_input: &str) -> Vec<Vec<MoveDir>> {
-    let mut button_moves: Vec<Vec<MoveDir>> = vec![];
-    for line in raw_input.lines() {
-        // Trim line and ignore acursor_loc: &Point2Dny empty lines
-        let line = line.trim();
+fn generate_input(raw_input: &str) -> Result<Vec<Vec<CardinalDirection>>, AocError> {
+    let mut button_moves: Vec<Vec<CardinalDirection>> = vec![];
+    for (line_index, raw_line) in raw_input.lines().enumerate() {
+        // Trim line and ignore any empty lines
+        let line = raw_line.trim();
         if line.is_empty() {
             break;
         }
         // Parse current line to determine movement directions
-        let mut single_button: Vec<MoveDir> = vec![];
-        for c in line.chars() {
+        let line_offset = raw_line.len() - raw_line.trim_start().len();
+        let mut single_button: Vec<CardinalDirection> = vec![];
+        for (i, c) in line.char_indices() {
+            let token = &line[i..i + c.len_utf8()];
             match c {
-                'U' => single_button.push(MoveDir::Up),
-                'D' => single_button.push(MoveDir::Down),
-                'L' => single_button.push(MoveDir::Left),
-                'R' => single_button.push(MoveDir::Right),
-                _ => panic!("D3: bad character in raw input!"),
+                'U' | 'D' | 'L' | 'R' => single_button.push(token.parse()?),
+                _ => return Err(AocError::parse_token(line_index, raw_line, line_offset + i, token, "invalid move direction")),
             }
         }
         button_moves.push(single_button);
     }
-    return button_moves;
+    return Ok(button_moves);
+}
+
+/// Keypad layout for Part 1 - a 3x3 square of digits.
+pub const SQUARE_KEYPAD: &str = "123\n456\n789\n";
+
+/// Keypad layout for Part 2 - a diamond of hexadecimal digits.
+pub const DIAMOND_KEYPAD: &str = "  1\n 234\n56789\n ABC\n  D\n";
+
+/// Represents a keypad of arbitrary shape. Locations without a key are holes that the cursor
+/// cannot move onto.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Keypad {
+    keys: Grid<Option<char>>,
+    start_loc: Point2D
 }
 
-/// Adjusts the given location to ensure it stays within the 3x3 square centred on (1, 1).
-fn adjust_cursor_location_bounds(cursor_loc: &Point2D) -> Point2D {
-    let mut adjusted_loc = *cursor_loc;
-    // Adjust x co-ord
-    if adjusted_loc.get_x() < 0 {
-        adjusted_loc.set_x(0);
-    } else if adjusted_loc.get_x() > 2 {
-        adjusted_loc.set_x(2);
+impl Keypad {
+    /// Parses a keypad from an ASCII drawing, with one line per row of keys. Spaces are holes and
+    /// any other character is a key. Empty lines are ignored and short lines are padded with holes.
+    /// The cursor starts on the given key, which must appear in the drawing exactly once (as must
+    /// every other key).
+    pub fn from_drawing(drawing: &str, start_key: char) -> Result<Keypad, AocError> {
+        let lines = drawing.lines().filter(|line| !line.is_empty()).collect::<Vec<&str>>();
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut rows: Vec<Vec<Option<char>>> = vec![];
+        let mut seen_keys: HashSet<char> = HashSet::new();
+        for (line_index, line) in drawing.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let mut row: Vec<Option<char>> = vec![None; width];
+            for (x, (i, c)) in line.char_indices().enumerate() {
+                if c == ' ' {
+                    continue;
+                }
+                if !seen_keys.insert(c) {
+                    let token = &line[i..i + c.len_utf8()];
+                    return Err(AocError::parse_token(line_index, line, i, token, "duplicate key"));
+                }
+                row[x] = Some(c);
+            }
+            rows.push(row);
+        }
+        let keys = Grid::from_rows(rows).unwrap();
+        let start_loc = match keys.iter().find(|(_, key)| **key == Some(start_key)) {
+            Some((loc, _)) => loc,
+            None => {
+                return Err(AocError::InvalidParameter(format!("start key {:?} is not on the keypad", start_key)));
+            }
+        };
+        return Ok(Keypad {
+            keys: keys,
+            start_loc: start_loc
+        });
+    }
+
+    /// Gets the location of the key the cursor starts on.
+    pub fn get_start(&self) -> Point2D {
+        return self.start_loc;
+    }
+
+    /// Gets the key at the given location, or None if the location is a hole or off the keypad.
+    pub fn get_key(&self, loc: &Point2D) -> Option<char> {
+        return self.keys.get(loc).copied().flatten();
     }
-    // Adjust y co-ord
-    if adjusted_loc.get_y() < 0 {
-        adjusted_loc.set_y(0);
-    } else if adjusted_loc.get_y() > 2 {
-        adjusted_loc.set_y(2);
+
+    /// Finds the location of the given key, if it is on the keypad.
+    pub fn find_key(&self, key: char) -> Option<Point2D> {
+        return self.keys.iter().find(|(_, k)| **k == Some(key)).map(|(loc, _)| loc);
+    }
+
+    /// Determines the location reached by making the move from the given location. The cursor
+    /// stays put if the move would leave the keypad or land on a hole.
+    pub fn make_move(&self, loc: &Point2D, movement: CardinalDirection) -> Point2D {
+        let peek_loc = *loc + movement.unit_vector();
+        if self.get_key(&peek_loc).is_none() {
+            return *loc;
+        }
+        return peek_loc;
+    }
+
+    /// Determines the code entered by following each line of moves from the start key, pressing
+    /// the key under the cursor at the end of each line.
+    pub fn enter_code(&self, input: &[Vec<CardinalDirection>]) -> String {
+        return self.trace_code(input).get_code();
+    }
+
+    /// Follows each line of moves from the start key, recording the cursor location after every
+    /// move, which moves were blocked and the key pressed at the end of each line.
+    pub fn trace_code(&self, input: &[Vec<CardinalDirection>]) -> KeypadTrace {
+        let mut lines: Vec<KeypadLine> = vec![];
+        let mut cursor_loc = self.start_loc;
+        for single_button in input {
+            let mut steps: Vec<KeypadStep> = vec![];
+            for movement in single_button {
+                let next_loc = self.make_move(&cursor_loc, *movement);
+                steps.push(KeypadStep {
+                    movement: *movement,
+                    loc: next_loc,
+                    blocked: next_loc == cursor_loc
+                });
+                cursor_loc = next_loc;
+            }
+            // Cursor only ever rests on keys, so there is always a key to press
+            lines.push(KeypadLine {
+                steps: steps,
+                key: self.get_key(&cursor_loc).unwrap()
+            });
+        }
+        return KeypadTrace {
+            start_loc: self.start_loc,
+            lines: lines
+        };
+    }
+
+    /// Renders one animation frame - a status line, the code entered so far and the keypad. The
+    /// key under the cursor is highlighted, in red if the latest move was blocked.
+    fn render_frame(&self, cursor_loc: &Point2D, blocked: bool, status: &str, code: &str) -> String {
+        let mut frame = format!("{}\nCode: {}\n\n", status, code);
+        for (y, row) in self.keys.rows().enumerate().take(self.keys.get_height()) {
+            for (x, key) in row.iter().enumerate() {
+                let is_cursor = Point2D::new(x as i64, y as i64) == *cursor_loc;
+                match key {
+                    Some(k) if is_cursor && blocked => frame.push_str(&format!("\x1b[1;41m {} \x1b[0m", k)),
+                    Some(k) if is_cursor => frame.push_str(&format!("\x1b[1;7m {} \x1b[0m", k)),
+                    Some(k) => frame.push_str(&format!(" {} ", k)),
+                    None => frame.push_str("   "),
+                }
+            }
+            frame.push('\n');
+        }
+        return frame;
+    }
+
+    /// Gets the moves available from the given location, along with the resulting locations.
+    /// Blocked moves are included, resulting in the cursor staying put.
+    fn get_moves(&self, loc: &Point2D) -> Vec<(CardinalDirection, Point2D)> {
+        return CardinalDirection::all()
+            .iter()
+            .map(|dir| (*dir, self.make_move(loc, *dir)))
+            .collect::<Vec<(CardinalDirection, Point2D)>>();
+    }
+
+    /// Finds a shortest non-empty sequence of moves taking the cursor from one location to another.
+    /// If the locations are the same, a single blocked move is used where possible, otherwise the
+    /// cursor steps out and back. Returns None if the destination cannot be reached.
+    pub fn find_move_sequence(&self, from: &Point2D, to: &Point2D) -> Option<Vec<CardinalDirection>> {
+        // Each state records the cursor location and the move that led to it
+        let result = breadth_first_search(
+            (*from, None),
+            |(loc, _)| {
+                self.get_moves(loc)
+                    .into_iter()
+                    .map(|(dir, next)| (next, Some(dir)))
+                    .collect::<Vec<(Point2D, Option<CardinalDirection>)>>()
+            },
+            |(loc, last_move)| loc == to && last_move.is_some(),
+        )?;
+        return Some(result.into_path().into_iter().filter_map(|(_, dir)| dir).collect());
+    }
+
+    /// Finds every shortest non-empty sequence of moves taking the cursor from one location to
+    /// another, in lexicographic order of the moves (north, east, south then west). Returns an
+    /// empty list if the destination cannot be reached.
+    pub fn find_all_move_sequences(&self, from: &Point2D, to: &Point2D) -> Vec<Vec<CardinalDirection>> {
+        let length = match self.find_move_sequence(from, to) {
+            Some(moves) => moves.len() as u64,
+            None => return vec![],
+        };
+        // Moves between keys are reversible, so distances from the destination are also distances
+        // to the destination
+        let distances = find_reachable_within(*to, length, |loc| {
+            self.get_moves(loc).into_iter().map(|(_, next)| next).collect::<Vec<Point2D>>()
+        });
+        let mut sequences: Vec<Vec<CardinalDirection>> = vec![];
+        self.extend_move_sequences(from, length, &distances, &mut vec![], &mut sequences);
+        return sequences;
+    }
+
+    /// Recursively extends the partial sequence with every move that can still reach the
+    /// destination in the remaining number of moves, recording each completed sequence.
+    fn extend_move_sequences(
+        &self,
+        loc: &Point2D,
+        remaining: u64,
+        distances: &HashMap<Point2D, u64>,
+        partial: &mut Vec<CardinalDirection>,
+        sequences: &mut Vec<Vec<CardinalDirection>>,
+    ) {
+        if remaining == 0 {
+            sequences.push(partial.clone());
+            return;
+        }
+        for (dir, next) in self.get_moves(loc) {
+            match distances.get(&next) {
+                Some(distance) if *distance < remaining => (),
+                _ => continue,
+            }
+            partial.push(dir);
+            self.extend_move_sequences(&next, remaining - 1, distances, partial, sequences);
+            partial.pop();
+        }
     }
-    return adjusted_loc;
 }
 
-/// Calculates the resulting button press from the cursor location.
-fn calculate_button_press(cursor_loc: &Point2D) -> String {
-    let result = 1 + cursor_loc.get_x() + 3 * cursor_loc.get_y();
-    return result.to_string();
+/// Represents a single move made while entering a code.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct KeypadStep {
+    movement: CardinalDirection,
+    loc: Point2D,
+    blocked: bool
 }
 
-/// Calculates the key locations for 2016 Day 2 Part 2.
-fn generate_key_locations_soph() -> HashMap<Point2D, char> {
-    let mut key_locations: HashMap<Point2D, char> = HashMap::new();
-    key_locations.insert(Point2D::new(2, 0), '1');
-    key_locations.insert(Point2D::new(1, 1), '2');
-    key_locations.insert(Point2D::new(2, 1), '3');
-    key_locations.insert(Point2D::new(3, 1), '4');
-    key_locations.insert(Point2D::new(0, 2), '5');
-    key_locations.insert(Point2D::new(1, 2), '6');
-    key_locations.insert(Point2D::new(2, 2), '7');
-    key_locations.insert(Point2D::new(3, 2), '8');
-    key_locations.insert(Point2D::new(4, 2), '9');
-    key_locations.insert(Point2D::new(1, 3), 'A');
-    key_locations.insert(Point2D::new(2, 3), 'B');
-    key_locations.insert(Point2D::new(3, 3), 'C');
-    key_locations.insert(Point2D::new(2, 4), 'D');
-    return key_locations;
+impl KeypadStep {
+    /// Gets the direction of the attempted move.
+    pub fn get_movement(&self) -> CardinalDirection {
+        return self.movement;
+    }
+
+    /// Gets the cursor location after the move.
+    pub fn get_loc(&self) -> Point2D {
+        return self.loc;
+    }
+
+    /// Checks if the move was blocked by the edge of the keypad, leaving the cursor in place.
+    pub fn is_blocked(&self) -> bool {
+        return self.blocked;
+    }
 }
 
-#[aoc(day2, part1)]
-fn solve_part_1(input: &Vec<Vec<MoveDir>>) -> String {
+/// Represents the moves made for one line of instructions and the key pressed at the end.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KeypadLine {
+    steps: Vec<KeypadStep>,
+    key: char
+}
+
+impl KeypadLine {
+    /// Gets the moves made for the line, in order.
+    pub fn get_steps(&self) -> &Vec<KeypadStep> {
+        return &self.steps;
+    }
+
+    /// Gets the key pressed at the end of the line.
+    pub fn get_key(&self) -> char {
+        return self.key;
+    }
+}
+
+/// Represents the full history of the cursor while entering a code.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct KeypadTrace {
+    start_loc: Point2D,
+    lines: Vec<KeypadLine>
+}
+
+impl KeypadTrace {
+    /// Gets the location of the cursor before any moves were made.
+    pub fn get_start(&self) -> Point2D {
+        return self.start_loc;
+    }
+
+    /// Gets the trace of each line of instructions, in order.
+    pub fn get_lines(&self) -> &Vec<KeypadLine> {
+        return &self.lines;
+    }
+
+    /// Gets the code entered, made up of the key pressed at the end of each line.
+    pub fn get_code(&self) -> String {
+        return self.lines.iter().map(|line| line.key).collect::<String>();
+    }
+
+    /// Gets every cursor location in order, one per move, starting with the start location.
+    pub fn get_cursor_history(&self) -> Vec<Point2D> {
+        let mut history = vec![self.start_loc];
+        history.extend(self.lines.iter().flat_map(|line| line.steps.iter().map(|step| step.loc)));
+        return history;
+    }
+
+    /// Gets the total number of moves that were blocked by the edge of the keypad.
+    pub fn get_blocked_count(&self) -> usize {
+        return self.lines.iter().flat_map(|line| line.steps.iter()).filter(|step| step.blocked).count();
+    }
+}
+
+/// Renders the trace as a series of terminal animation frames using ANSI colours - one for the
+/// starting position, one per move and one per key press.
+pub fn render_trace_frames(keypad: &Keypad, trace: &KeypadTrace) -> Vec<String> {
+    let mut frames = vec![keypad.render_frame(&trace.start_loc, false, "Start", "")];
     let mut code = String::new();
-    let mut cursor_loc = Point2D::new(1, 1); // Corresponds to "5" key as starting point
-    for single_button in input {
-        for movement in single_button {
-            // Move cursor and adjust to stay within 3x3 square centred on "5" key at (1, 1)
-            let unit_vec = movement.get_unit_vector();
-            cursor_loc.move_point(unit_vec.0, unit_vec.1);
-            cursor_loc = adjust_cursor_location_bounds(&cursor_loc);
+    let mut cursor_loc = trace.start_loc;
+    let total_lines = trace.lines.len();
+    for (line_index, line) in trace.lines.iter().enumerate() {
+        for (step_index, step) in line.steps.iter().enumerate() {
+            let status = format!(
+                "Line {}/{}, move {}/{}: {}{}",
+                line_index + 1,
+                total_lines,
+                step_index + 1,
+                line.steps.len(),
+                get_move_letter(step.movement),
+                if step.blocked { " (blocked)" } else { "" }
+            );
+            cursor_loc = step.loc;
+            frames.push(keypad.render_frame(&cursor_loc, step.blocked, &status, &code));
         }
-        // Determine key from resulting code
-        let button_press = calculate_button_press(&cursor_loc);
-        code.push_str(&button_press);
+        code.push(line.key);
+        let status = format!("Line {}/{}: pressed {}", line_index + 1, total_lines, line.key);
+        frames.push(keypad.render_frame(&cursor_loc, false, &status, &code));
     }
-    return code;
+    return frames;
 }
 
-#[aoc(day2, part2)]
-fn solve_part_2(input: &Vec<Vec<MoveDir>>) -> String {
-    let mut code = String::new();
-    let key_locations = generate_key_locations_soph();
-    // Starting location key "5" at location (0, 2) in new layout
-    let mut cursor_loc = Point2D::new(0, 2);
-    for single_button in input {
+/// Plays the frames in the terminal, clearing the screen before each frame and pausing for the
+/// given delay after it.
+pub fn animate_trace_frames<W: Write>(out: &mut W, frames: &[String], frame_delay: Duration) -> io::Result<()> {
+    for frame in frames {
+        write!(out, "\x1b[H\x1b[2J{}", frame)?;
+        out.flush()?;
+        thread::sleep(frame_delay);
+    }
+    return Ok(());
+}
+
+/// Finds the location of every key in the code, starting with the start location of the keypad.
+fn locate_code_keys(keypad: &Keypad, code: &str) -> Result<Vec<Point2D>, AocError> {
+    let mut key_locs = vec![keypad.get_start()];
+    for key in code.chars() {
+        match keypad.find_key(key) {
+            Some(loc) => key_locs.push(loc),
+            None => return Err(AocError::InvalidParameter(format!("key {:?} is not on the keypad", key))),
+        }
+    }
+    return Ok(key_locs);
+}
+
+/// Derives the shortest instructions that enter the given code on the keypad, with one line of
+/// moves per key. Every line is non-empty, so the instructions survive formatting and re-parsing.
+pub fn derive_instructions(keypad: &Keypad, code: &str) -> Result<Vec<Vec<CardinalDirection>>, AocError> {
+    let key_locs = locate_code_keys(keypad, code)?;
+    let mut instructions: Vec<Vec<CardinalDirection>> = vec![];
+    for pair in key_locs.windows(2) {
+        match keypad.find_move_sequence(&pair[0], &pair[1]) {
+            Some(moves) => instructions.push(moves),
+            None => return Err(AocError::Unsolvable(format!("key at {:?} cannot be reached", pair[1]))),
+        }
+    }
+    return Ok(instructions);
+}
+
+/// Derives every shortest line of moves for each key in the given code. Any combination of one
+/// line per key enters the code.
+pub fn derive_all_instructions(keypad: &Keypad, code: &str) -> Result<Vec<Vec<Vec<CardinalDirection>>>, AocError> {
+    let key_locs = locate_code_keys(keypad, code)?;
+    let mut options: Vec<Vec<Vec<CardinalDirection>>> = vec![];
+    for pair in key_locs.windows(2) {
+        let sequences = keypad.find_all_move_sequences(&pair[0], &pair[1]);
+        if sequences.is_empty() {
+            return Err(AocError::Unsolvable(format!("key at {:?} cannot be reached", pair[1])));
+        }
+        options.push(sequences);
+    }
+    return Ok(options);
+}
+
+/// Gets the letter used for the move in the puzzle input.
+fn get_move_letter(movement: CardinalDirection) -> char {
+    match movement {
+        CardinalDirection::North => return 'U',
+        CardinalDirection::East => return 'R',
+        CardinalDirection::South => return 'D',
+        CardinalDirection::West => return 'L',
+    }
+}
+
+/// Formats the instructions in the puzzle input format, using U/D/L/R for the moves.
+pub fn format_instructions(instructions: &[Vec<CardinalDirection>]) -> String {
+    let mut output = String::new();
+    for single_button in instructions {
         for movement in single_button {
-            let unit_vec = movement.get_unit_vector();
-            // Check if next location is on the keypad
-            let peek_loc = cursor_loc.peek_point(unit_vec.0, unit_vec.1);
-            if !key_locations.contains_key(&peek_loc) {
-                continue;
-            }
-            // Movement stayed on keypad, so update cursor location
-            cursor_loc = peek_loc;
+            output.push(get_move_letter(*movement));
         }
-        // Determine button press for current line of instructions
-        code.push(*key_locations.get(&cursor_loc).unwrap());
+        output.push('\n');
+    }
+    return output;
+}
+
+#[aoc(day2, part1)]
+fn solve_part_1(input: &Vec<Vec<CardinalDirection>>) -> String {
+    let keypad = Keypad::from_drawing(SQUARE_KEYPAD, '5').unwrap();
+    return keypad.enter_code(input);
+}
+
+#[aoc(day2, part2)]
+fn solve_part_2(input: &Vec<Vec<CardinalDirection>>) -> String {
+    let keypad = Keypad::from_drawing(DIAMOND_KEYPAD, '5').unwrap();
+    return keypad.enter_code(input);
+}
+
+/// Solution for AOC 2016 Day 2, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<CardinalDirection>>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn get_day(&self) -> u32 {
+        return 2;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return generate_input(raw_input);
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return Ok(solve_part_2(input));
     }
-    return code;
 }
 
 #[cfg(test)]
@@ -137,15 +459,103 @@ mod tests {
 
     #[test]
     fn test_d02_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day2.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day2.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!("78985", result);
     }
 
     #[test]
     fn test_d02_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day2.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day2.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!("57DD8", result);
     }
+
+    #[test]
+    fn test_d02_custom_keypad() {
+        // Phone layout with a ragged last line
+        let keypad = Keypad::from_drawing("123\n456\n789\n*0#\n 0", '0').err();
+        assert_eq!(
+            Some(AocError::Parse {
+                line: 5,
+                column: 2,
+                text: String::from("0"),
+                reason: String::from("duplicate key"),
+            }),
+            keypad
+        );
+        let keypad = Keypad::from_drawing("123\n456\n789\n 0", '5').unwrap();
+        assert_eq!(Some(Point2D::new(1, 3)), keypad.find_key('0'));
+        assert_eq!(None, keypad.get_key(&Point2D::new(2, 3)));
+        let input = generate_input("DDD\nRDR\nULLLU").unwrap();
+        assert_eq!("004", keypad.enter_code(&input));
+        assert!(Keypad::from_drawing(SQUARE_KEYPAD, 'X').is_err());
+    }
+
+    #[test]
+    fn test_d02_derive_round_trip() {
+        let square = Keypad::from_drawing(SQUARE_KEYPAD, '5').unwrap();
+        let diamond = Keypad::from_drawing(DIAMOND_KEYPAD, '5').unwrap();
+        for (keypad, code) in [(&square, "78985"), (&square, "5511"), (&diamond, "57DD8"), (&diamond, "1D55")] {
+            let instructions = derive_instructions(keypad, code).unwrap();
+            let input = generate_input(&format_instructions(&instructions)).unwrap();
+            assert_eq!(code, keypad.enter_code(&input));
+        }
+        let raw_input = format_instructions(&derive_instructions(&square, "1397").unwrap());
+        assert_eq!("UL\nRR\nDD\nLL\n", raw_input);
+        assert_eq!("1397", solve_part_1(&generate_input(&raw_input).unwrap()));
+    }
+
+    #[test]
+    fn test_d02_derive_repeated_keys() {
+        // Corner keys can repeat with a single blocked move, but the centre key needs two moves
+        let square = Keypad::from_drawing(SQUARE_KEYPAD, '1').unwrap();
+        let instructions = derive_instructions(&square, "1155").unwrap();
+        assert_eq!(vec![1, 1, 2, 2], instructions.iter().map(|line| line.len()).collect::<Vec<usize>>());
+        let options = derive_all_instructions(&square, "15").unwrap();
+        assert_eq!(2, options[0].len());
+        assert_eq!(
+            vec![
+                vec![CardinalDirection::East, CardinalDirection::South],
+                vec![CardinalDirection::South, CardinalDirection::East],
+            ],
+            options[1]
+        );
+        let centre = square.find_key('5').unwrap();
+        assert_eq!(4, square.find_all_move_sequences(&centre, &centre).len());
+        assert!(derive_instructions(&square, "1X").is_err());
+    }
+
+    #[test]
+    fn test_d02_trace_code() {
+        let keypad = Keypad::from_drawing(SQUARE_KEYPAD, '5').unwrap();
+        let input = generate_input("ULL\nRRDDD\n").unwrap();
+        let trace = keypad.trace_code(&input);
+        assert_eq!("19", trace.get_code());
+        assert_eq!(2, trace.get_blocked_count());
+        assert_eq!(9, trace.get_cursor_history().len());
+        let line = &trace.get_lines()[1];
+        assert_eq!('9', line.get_key());
+        assert_eq!(
+            vec![false, false, false, false, true],
+            line.get_steps().iter().map(|step| step.is_blocked()).collect::<Vec<bool>>()
+        );
+        assert_eq!(Point2D::new(2, 2), line.get_steps()[4].get_loc());
+    }
+
+    #[test]
+    fn test_d02_render_trace_frames() {
+        let keypad = Keypad::from_drawing(DIAMOND_KEYPAD, '5').unwrap();
+        let trace = keypad.trace_code(&generate_input("LR\n").unwrap());
+        let frames = render_trace_frames(&keypad, &trace);
+        // Start frame, one frame per move and one frame for the key press
+        assert_eq!(4, frames.len());
+        assert!(frames[1].starts_with("Line 1/1, move 1/2: L (blocked)\nCode: \n"));
+        assert!(frames[1].contains("\x1b[1;41m 5 \x1b[0m 6  7  8  9 \n"));
+        assert!(frames[3].starts_with("Line 1/1: pressed 6\nCode: 6\n"));
+        assert!(frames[3].contains("\n       1       \n"));
+        let mut output: Vec<u8> = vec![];
+        animate_trace_frames(&mut output, &frames, Duration::from_millis(0)).unwrap();
+        assert_eq!(4, String::from_utf8(output).unwrap().matches("\x1b[2J").count());
+    }
 }
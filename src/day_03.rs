@@ -1,27 +1,233 @@
-#[aoc_generator(day3)]
-fn generate_input(raw_input: &str) -> Vec<(u64, u64, u64)> {
-    let mut triangle_candidates: Vec<(u64, u64, u64)> = vec![];
-    for line in raw_input.lines() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{BufRead, Lines};
+
+use super::solution::Solution;
+use super::utils::error::AocError;
+
+/// Represents the classification of a set of three side lengths.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TriangleKind {
+    /// All three sides are the same length.
+    Equilateral,
+    /// Exactly two sides are the same length.
+    Isosceles,
+    /// All three sides are different lengths.
+    Scalene,
+    /// The longest side is equal to the sum of the other two, so the triangle is flat.
+    Degenerate,
+    /// The longest side is longer than the sum of the other two, so no triangle can be formed.
+    Invalid,
+}
+
+impl TriangleKind {
+    /// Checks if the classification is for a valid triangle IAW rules specified in AOC 2016 Day 3.
+    pub fn is_valid(&self) -> bool {
+        return !matches!(self, TriangleKind::Degenerate | TriangleKind::Invalid);
+    }
+}
+
+/// Represents a single row of values from a table, along with the (zero-based) index of the input
+/// line it was read from.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TableRow<T> {
+    line_index: usize,
+    values: Vec<T>
+}
+
+impl<T> TableRow<T> {
+    /// Creates a new table row read from the given input line.
+    pub fn new(line_index: usize, values: Vec<T>) -> Self {
+        Self {
+            line_index: line_index,
+            values: values
         }
-        // Split up line and add to candidate list
-        let values = line.split_ascii_whitespace().map(|x| x.parse::<u64>().unwrap()).collect::<Vec<u64>>();
-        if values.len() != 3 {
-            panic!("Day 3 - invalid number of values in line.");
+    }
+
+    /// Gets the (zero-based) index of the input line the row was read from.
+    pub fn get_line_index(&self) -> usize {
+        return self.line_index;
+    }
+
+    /// Gets the values in the row.
+    pub fn get_values(&self) -> &Vec<T> {
+        return &self.values;
+    }
+}
+
+impl<T: fmt::Display> TableRow<T> {
+    /// Creates a parse error for the row as a whole, reporting its values as the offending text.
+    fn to_parse_error(&self, reason: &str) -> AocError {
+        return AocError::Parse {
+            line: self.line_index + 1,
+            column: 1,
+            text: self.values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(" "),
+            reason: reason.to_string(),
+        };
+    }
+}
+
+/// Iterator that parses rows of whitespace-separated numbers from a reader, one line at a time, so
+/// the whole input does not need to be held in memory. Empty lines are skipped. Iteration stops
+/// after the first error.
+pub struct RowReader<R> {
+    lines: Lines<R>,
+    line_index: usize,
+    width: Option<usize>,
+    failed: bool
+}
+
+impl<R: BufRead> RowReader<R> {
+    /// Creates a new row reader. If a width is given, every row must contain exactly that many
+    /// values.
+    pub fn new(reader: R, width: Option<usize>) -> Self {
+        Self {
+            lines: reader.lines(),
+            line_index: 0,
+            width: width,
+            failed: false
         }
-        triangle_candidates.push((values[0], values[1], values[2]));
     }
-    return triangle_candidates;
+
+    /// Parses the values in a single line, checking the row width if one is given.
+    fn parse_row(line_index: usize, line: &str, width: Option<usize>) -> Result<TableRow<u64>, AocError> {
+        let mut values: Vec<u64> = vec![];
+        // Track the byte offset of each token so errors can report its column
+        let mut search_from = 0;
+        for token in line.split_ascii_whitespace() {
+            let offset = search_from + line[search_from..].find(token).unwrap();
+            search_from = offset + token.len();
+            match token.parse::<u64>() {
+                Ok(value) => values.push(value),
+                Err(_) => return Err(AocError::parse_token(line_index, line, offset, token, "invalid side length")),
+            }
+        }
+        if let Some(width) = width {
+            if values.len() != width {
+                let reason = format!("expected {} values", width);
+                let offset = line.len() - line.trim_start().len();
+                return Err(AocError::parse_token(line_index, line, offset, line.trim(), &reason));
+            }
+        }
+        return Ok(TableRow::new(line_index, values));
+    }
+}
+
+impl<R: BufRead> Iterator for RowReader<R> {
+    type Item = Result<TableRow<u64>, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        for line in self.lines.by_ref() {
+            let result = match line {
+                Ok(line) if line.trim().is_empty() => None,
+                Ok(line) => Some(Self::parse_row(self.line_index, &line, self.width)),
+                Err(e) => Some(Err(AocError::Io(e.to_string()))),
+            };
+            self.line_index += 1;
+            if let Some(result) = result {
+                self.failed = result.is_err();
+                return Some(result);
+            }
+        }
+        return None;
+    }
+}
+
+/// Iterator that regroups a table with N columns by reading down the columns in blocks of N rows,
+/// yielding N groups per block - one per column. Rows are pulled from the underlying iterator one
+/// block at a time. Yields a parse error (for the line of the offending row) if a row does not
+/// have exactly N values or the rows run out part way through a block, and stops after the first
+/// error.
+pub struct ColumnRegrouper<I, T> {
+    rows: I,
+    width: usize,
+    pending: VecDeque<Vec<T>>,
+    failed: bool
+}
+
+impl<I, T> ColumnRegrouper<I, T>
+where
+    I: Iterator<Item = Result<TableRow<T>, AocError>>,
+    T: Clone + fmt::Display,
+{
+    /// Creates a new regrouper for a table with the given number of columns.
+    pub fn new(rows: I, width: usize) -> Self {
+        Self {
+            rows: rows,
+            width: width,
+            pending: VecDeque::new(),
+            failed: false
+        }
+    }
+
+    /// Reads the next block of rows and queues up its columns. Returns None once the rows run out
+    /// at the end of a block.
+    fn read_block(&mut self) -> Option<Result<(), AocError>> {
+        let mut block: Vec<TableRow<T>> = Vec::with_capacity(self.width);
+        while block.len() < self.width {
+            match self.rows.next() {
+                Some(Ok(row)) => {
+                    if row.get_values().len() != self.width {
+                        let reason = format!("expected {} values", self.width);
+                        return Some(Err(row.to_parse_error(&reason)));
+                    }
+                    block.push(row);
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None if block.is_empty() => return None,
+                None => {
+                    // Report the first row that could not be placed in a complete block
+                    let reason = format!("row left over after the last complete block of {}", self.width);
+                    return Some(Err(block[0].to_parse_error(&reason)));
+                }
+            }
+        }
+        for x in 0..self.width {
+            self.pending.push_back(block.iter().map(|row| row.values[x].clone()).collect::<Vec<T>>());
+        }
+        return Some(Ok(()));
+    }
+}
+
+impl<I, T> Iterator for ColumnRegrouper<I, T>
+where
+    I: Iterator<Item = Result<TableRow<T>, AocError>>,
+    T: Clone + fmt::Display,
+{
+    type Item = Result<Vec<T>, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        if self.pending.is_empty() {
+            match self.read_block()? {
+                Ok(()) => (),
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        return self.pending.pop_front().map(Ok);
+    }
+}
+
+#[aoc_generator(day3)]
+fn generate_input(raw_input: &str) -> Result<Vec<TableRow<u64>>, AocError> {
+    return RowReader::new(raw_input.as_bytes(), Some(3)).collect::<Result<Vec<TableRow<u64>>, AocError>>();
 }
 
 #[aoc(day3, part1)]
-fn solve_part_1(input: &Vec<(u64, u64, u64)>) -> u64 {
+fn solve_part_1(input: &Vec<TableRow<u64>>) -> u64 {
     let mut count = 0;
-    for (a, b, c) in input {
+    for row in input {
+        let sides = row.get_values();
         // Check if any of the side combinations indicate an "impossible" triangle
-        if check_triangle_validity(*a, *b, *c) {
+        if check_triangle_validity(sides[0], sides[1], sides[2]) {
             count += 1;
         }
     }
@@ -29,52 +235,323 @@ fn solve_part_1(input: &Vec<(u64, u64, u64)>) -> u64 {
 }
 
 #[aoc(day3, part2)]
-fn solve_part_2(input: &Vec<(u64, u64, u64)>) -> u64 {
-    // Realign the triangles using the verticle rule
-    let mut new_input: Vec<(u64, u64, u64)> = vec![];
-    for i in 0..(input.len() / 3) {
-        let line_0 = input[3 * i];
-        let line_1 = input[3 * i + 1];
-        let line_2 = input[3 * i + 2];
-        new_input.push((line_0.0, line_1.0, line_2.0));
-        new_input.push((line_0.1, line_1.1, line_2.1));
-        new_input.push((line_0.2, line_1.2, line_2.2));
-    }
-    // Now check triangle validity
+fn solve_part_2(input: &Vec<TableRow<u64>>) -> Result<u64, AocError> {
+    // Realign the triangles using the vertical rule
+    return count_valid_triangles(ColumnRegrouper::new(input.iter().cloned().map(Ok), 3));
+}
+
+/// Counts the valid triangles in the given sequence of side lengths, stopping at the first error.
+fn count_valid_triangles<I: Iterator<Item = Result<Vec<u64>, AocError>>>(triangles: I) -> Result<u64, AocError> {
     let mut count = 0;
-    for (a, b, c) in new_input {
-        if check_triangle_validity(a, b, c) {
+    for sides in triangles {
+        let sides = sides?;
+        if check_triangle_validity(sides[0], sides[1], sides[2]) {
             count += 1;
         }
     }
-    return count;
+    return Ok(count);
+}
+
+/// Counts the valid triangles in the input read from the given reader, one line at a time. If
+/// reading by columns, the triangles are read down the columns in blocks of three rows (as in Part
+/// 2), otherwise each row is a triangle (as in Part 1).
+pub fn count_valid_triangles_streamed<R: BufRead>(reader: R, by_columns: bool) -> Result<u64, AocError> {
+    let rows = RowReader::new(reader, Some(3));
+    if by_columns {
+        return count_valid_triangles(ColumnRegrouper::new(rows, 3));
+    }
+    return count_valid_triangles(rows.map(|row| row.map(|row| row.values)));
+}
+
+/// Represents a failed triangle inequality - the sum of two sides is not greater than the third.
+/// Sides are named a, b and c in the order they were given.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct TriangleViolation {
+    names: [char; 3],
+    lengths: [u64; 3]
+}
+
+impl TriangleViolation {
+    /// Gets the names of the two sides whose sum was not greater than the third side.
+    pub fn get_summed_sides(&self) -> (char, char) {
+        return (self.names[0], self.names[1]);
+    }
+
+    /// Gets the name of the side that was too long.
+    pub fn get_long_side(&self) -> char {
+        return self.names[2];
+    }
+
+    /// Gets the sum of the two sides compared against the third. Widened so the sum cannot
+    /// overflow.
+    pub fn get_sum(&self) -> u128 {
+        return self.lengths[0] as u128 + self.lengths[1] as u128;
+    }
+
+    /// Gets the length of the side that was too long.
+    pub fn get_long_length(&self) -> u64 {
+        return self.lengths[2];
+    }
+}
+
+impl fmt::Display for TriangleViolation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(
+            f,
+            "{} + {} <= {} ({} + {} = {} <= {})",
+            self.names[0],
+            self.names[1],
+            self.names[2],
+            self.lengths[0],
+            self.lengths[1],
+            self.get_sum(),
+            self.lengths[2]
+        );
+    }
+}
+
+/// Validates the triangle specified by the given sides IAW rules specified in AOC 2016 Day 3,
+/// returning the first inequality that failed (checked in the order a + b > c, b + c > a, then
+/// c + a > b). Safe for side lengths across the full u64 range.
+pub fn validate_triangle(a: u64, b: u64, c: u64) -> Result<(), TriangleViolation> {
+    let checks = [(['a', 'b', 'c'], [a, b, c]), (['b', 'c', 'a'], [b, c, a]), (['c', 'a', 'b'], [c, a, b])];
+    for (names, lengths) in checks {
+        // A sum too large for u64 is always greater than the remaining side
+        match lengths[0].checked_add(lengths[1]) {
+            Some(sum) if sum <= lengths[2] => {
+                return Err(TriangleViolation {
+                    names: names,
+                    lengths: lengths
+                });
+            }
+            _ => (),
+        }
+    }
+    return Ok(());
 }
 
 /// Checks if the triangle specified by the given sides is valid IAW rules specified in AOC 2016
 /// Day 3.
 fn check_triangle_validity(a: u64, b: u64, c: u64) -> bool {
-    if a + b <= c || b + c <= a || c + a <= b {
+    return validate_triangle(a, b, c).is_ok();
+}
+
+/// Finds every invalid triangle in the input, along with the (zero-based) index of its input line
+/// and the inequality it failed.
+pub fn find_triangle_violations(triangles: &[TableRow<u64>]) -> Vec<(usize, TriangleViolation)> {
+    return triangles
+        .iter()
+        .filter_map(|row| {
+            let sides = row.get_values();
+            return validate_triangle(sides[0], sides[1], sides[2]).err().map(|v| (row.get_line_index(), v));
+        })
+        .collect::<Vec<(usize, TriangleViolation)>>();
+}
+
+/// Classifies the triangle specified by the given sides.
+pub fn classify_triangle(a: u64, b: u64, c: u64) -> TriangleKind {
+    let mut sides = [a, b, c];
+    sides.sort_unstable();
+    // Widen before adding, as the sum of two u64 values can overflow
+    let short_sum = sides[0] as u128 + sides[1] as u128;
+    let long_side = sides[2] as u128;
+    if short_sum < long_side {
+        return TriangleKind::Invalid;
+    } else if short_sum == long_side {
+        return TriangleKind::Degenerate;
+    } else if sides[0] == sides[2] {
+        return TriangleKind::Equilateral;
+    } else if sides[0] == sides[1] || sides[1] == sides[2] {
+        return TriangleKind::Isosceles;
+    }
+    return TriangleKind::Scalene;
+}
+
+/// Checks if the given sides can form a polygon - there must be at least three sides, and each
+/// side must be shorter than the sum of all the others.
+pub fn check_polygon_validity(sides: &[u64]) -> bool {
+    if sides.len() < 3 {
         return false;
     }
-    return true;
+    // Widen before summing, as the total of several u64 values can overflow
+    let total: u128 = sides.iter().map(|side| *side as u128).sum();
+    return sides.iter().all(|side| (*side as u128) < total - *side as u128);
+}
+
+/// Solution for AOC 2016 Day 3, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<TableRow<u64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn get_day(&self) -> u32 {
+        return 3;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return generate_input(raw_input);
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return solve_part_2(input);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs::*;
+    use std::io::BufReader;
+    use crate::utils::rng::XorShift;
 
     #[test]
     fn test_d03_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day3.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day3.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(862, result);
     }
 
     #[test]
     fn test_d03_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day3.txt").unwrap());
-        let result = solve_part_2(&input);
+        let input = generate_input(&read_to_string("./input/2016/day3.txt").unwrap()).unwrap();
+        let result = solve_part_2(&input).unwrap();
         assert_eq!(1577, result);
     }
+
+    #[test]
+    fn test_d03_invalid_input() {
+        let error = generate_input("  1 2 3\n  4 -5 6\n").err().unwrap();
+        assert_eq!(
+            AocError::Parse {
+                line: 2,
+                column: 5,
+                text: String::from("-5"),
+                reason: String::from("invalid side length"),
+            },
+            error
+        );
+        assert!(generate_input("1 2 3 4\n").is_err());
+    }
+
+    #[test]
+    fn test_d03_streamed_proper() {
+        let reader = BufReader::new(File::open("./input/2016/day3.txt").unwrap());
+        assert_eq!(Ok(862), count_valid_triangles_streamed(reader, false));
+        let reader = BufReader::new(File::open("./input/2016/day3.txt").unwrap());
+        assert_eq!(Ok(1577), count_valid_triangles_streamed(reader, true));
+    }
+
+    #[test]
+    fn test_d03_regroup_columns() {
+        let rows = RowReader::new("1 2 3 4\n5 6 7 8\n\n9 10 11 12\n13 14 15 16\n".as_bytes(), None);
+        let columns = ColumnRegrouper::new(rows, 4).collect::<Result<Vec<Vec<u64>>, AocError>>().unwrap();
+        assert_eq!(vec![vec![1, 5, 9, 13], vec![2, 6, 10, 14], vec![3, 7, 11, 15], vec![4, 8, 12, 16]], columns);
+        // Ragged rows and trailing partial blocks are errors rather than being dropped
+        let rows = RowReader::new("1 2\n\n3 4 5\n".as_bytes(), None);
+        let mut regrouper = ColumnRegrouper::new(rows, 2);
+        assert_eq!(
+            Some(Err(AocError::Parse {
+                line: 3,
+                column: 1,
+                text: String::from("3 4 5"),
+                reason: String::from("expected 2 values"),
+            })),
+            regrouper.next()
+        );
+        assert_eq!(None, regrouper.next());
+        let input = generate_input("1 2 3\n4 5 6\n7 8 9\n\n10 11 12\n13 14 15\n").unwrap();
+        assert_eq!(
+            Err(AocError::Parse {
+                line: 5,
+                column: 1,
+                text: String::from("10 11 12"),
+                reason: String::from("row left over after the last complete block of 3"),
+            }),
+            solve_part_2(&input)
+        );
+    }
+
+    #[test]
+    fn test_d03_classify_triangle() {
+        assert_eq!(TriangleKind::Equilateral, classify_triangle(4, 4, 4));
+        assert_eq!(TriangleKind::Isosceles, classify_triangle(5, 3, 5));
+        assert_eq!(TriangleKind::Scalene, classify_triangle(3, 4, 5));
+        assert_eq!(TriangleKind::Degenerate, classify_triangle(2, 5, 3));
+        assert_eq!(TriangleKind::Invalid, classify_triangle(5, 10, 25));
+        for (a, b, c) in [(4, 4, 4), (5, 3, 5), (3, 4, 5), (2, 5, 3), (5, 10, 25), (0, 0, 0)] {
+            assert_eq!(check_triangle_validity(a, b, c), classify_triangle(a, b, c).is_valid());
+            assert_eq!(check_triangle_validity(a, b, c), check_polygon_validity(&[a, b, c]));
+        }
+    }
+
+    #[test]
+    fn test_d03_polygon_validity() {
+        assert!(check_polygon_validity(&[1, 1, 1, 1]));
+        assert!(check_polygon_validity(&[2, 3, 4, 8]));
+        assert!(!check_polygon_validity(&[2, 3, 4, 9]));
+        assert!(!check_polygon_validity(&[5, 5]));
+    }
+
+    /// Reference validity check with every sum widened to u128.
+    fn check_triangle_validity_u128(a: u64, b: u64, c: u64) -> bool {
+        let (a, b, c) = (a as u128, b as u128, c as u128);
+        return a + b > c && b + c > a && c + a > b;
+    }
+
+    #[test]
+    fn test_d03_validity_large_sides() {
+        let max = u64::MAX;
+        assert!(check_triangle_validity(max, max, max));
+        assert!(check_triangle_validity(max, max - 1, 2));
+        assert!(!check_triangle_validity(max, max - 1, 1));
+        assert_eq!(TriangleKind::Equilateral, classify_triangle(max, max, max));
+        assert_eq!(TriangleKind::Degenerate, classify_triangle(max, max - 1, 1));
+        assert!(check_polygon_validity(&[max, max, max, max]));
+    }
+
+    #[test]
+    fn test_d03_validity_matches_reference() {
+        // Deterministic pseudo-random side lengths, mixing small values with values close to the
+        // top of the u64 range so both sums and overflows are exercised
+        let mut rng = XorShift::new(0x5eed_2016);
+        let mut next_side = || {
+            let seed = rng.next_u64();
+            match seed % 4 {
+                0 => seed % 16,
+                1 => u64::MAX - seed % 16,
+                2 => u64::MAX / 2 + seed % 3 - 1,
+                _ => seed,
+            }
+        };
+        for _ in 0..100_000 {
+            let (a, b, c) = (next_side(), next_side(), next_side());
+            let expected = check_triangle_validity_u128(a, b, c);
+            assert_eq!(expected, check_triangle_validity(a, b, c), "sides {} {} {}", a, b, c);
+            assert_eq!(expected, classify_triangle(a, b, c).is_valid());
+            assert_eq!(expected, check_polygon_validity(&[a, b, c]));
+            if let Err(violation) = validate_triangle(a, b, c) {
+                assert!(violation.get_sum() <= violation.get_long_length() as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn test_d03_violation_report() {
+        assert_eq!(Ok(()), validate_triangle(3, 4, 5));
+        let violation = validate_triangle(5, 25, 10).err().unwrap();
+        assert_eq!(('c', 'a'), violation.get_summed_sides());
+        assert_eq!('b', violation.get_long_side());
+        assert_eq!("c + a <= b (10 + 5 = 15 <= 25)", violation.to_string());
+        let input = generate_input("3 4 5\n\n1 1 2\n").unwrap();
+        let violations = find_triangle_violations(&input);
+        assert_eq!(1, violations.len());
+        assert_eq!(2, violations[0].0);
+        assert_eq!("a + b <= c (1 + 1 = 2 <= 2)", violations[0].1.to_string());
+    }
 }
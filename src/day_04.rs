@@ -1,10 +1,15 @@
-use std::char;
 use std::collections::HashMap;
-use itertools::Itertools;
+use std::fmt;
 use regex::Regex;
+use serde_json::{json, Value};
+use super::solution::Solution;
+use super::utils::cipher;
+use super::utils::error::AocError;
+use super::utils::frequency::{FrequencyCounter, TieBreak};
 
 /// Represents room data, including its encrypted name, sector ID and listed checksum.
-struct RoomData {
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RoomData {
     encrypted_name: String,
     sector_id: u64,
     checksum: String
@@ -19,7 +24,6 @@ impl RoomData {
         }
     }
 
-    #[allow(dead_code)]
     pub fn get_encrypted_name(&self) -> String {
         return self.encrypted_name.to_string();
     }
@@ -28,100 +32,356 @@ impl RoomData {
         return self.sector_id;
     }
 
-    #[allow(dead_code)]
     pub fn get_checksum(&self) -> String {
         return self.checksum.to_string();
     }
 
     /// Decrypts the room name, using method specified in AOC 2016 Day 4 Part 2.
     pub fn decrypt_name(&self) -> String {
-        let mut decrypted_name = String::new();
-        for c in self.encrypted_name.chars() {
-            if c == '-' {
-                // Dashes decrypt to spaces
-                decrypted_name.push(' ');
-            } else {
-                // Rotate letter forward number of times equal to sector ID
-                let mut decrypt = c as u32;
-                for _ in 0..self.sector_id {
-                    decrypt += 1;
-                    if decrypt > 'z' as u32 {
-                        decrypt = 'a' as u32;
-                    }
-                }
-                decrypted_name.push(char::from_u32(decrypt).unwrap());
-            }
-        }
-        return decrypted_name;
+        // Letters rotate forward by the sector ID and dashes decrypt to spaces
+        let shift = (self.sector_id % 26) as i64;
+        return cipher::shift_text(&self.encrypted_name, shift).replace('-', " ");
     }
 
-    /// Calculates the checksum of room from its encrypted name.
+    /// Calculates the checksum of room from its encrypted name, using the standard policy.
     pub fn calculate_checksum(&self) -> String {
-        let encrypted_name_chars = self.encrypted_name.replace("-", "");
-        let mut char_counts: HashMap<char, u64> = HashMap::new();
-        for c in encrypted_name_chars.chars() {
-            if char_counts.contains_key(&c) {
-                *char_counts.get_mut(&c).unwrap() += 1;
-            } else {
-                char_counts.insert(c, 1);
-            }
+        return self.calculate_checksum_with(&ChecksumPolicy::standard());
+    }
+
+    /// Calculates the checksum of room from its encrypted name - the most common letters in the
+    /// name, up to the length and with ties broken as given by the policy.
+    pub fn calculate_checksum_with(&self, policy: &ChecksumPolicy) -> String {
+        let counter = FrequencyCounter::from_items(self.encrypted_name.chars().filter(|c| *c != '-'));
+        return counter.top_k(policy.length, policy.tie_break).into_iter().collect::<String>();
+    }
+
+    /// Calculates checksum from encrypted name and checks if it matches the original checksum.
+    pub fn validate_checksum(&self) -> bool {
+        return self.validate_checksum_with(&ChecksumPolicy::standard());
+    }
+
+    /// Calculates checksum from encrypted name using the given policy and checks if it matches the
+    /// original checksum.
+    pub fn validate_checksum_with(&self, policy: &ChecksumPolicy) -> bool {
+        let calculated_checksum = self.calculate_checksum_with(policy);
+        return calculated_checksum == self.checksum;
+    }
+}
+
+/// Represents the rules for calculating a room checksum - how many letters it contains and how
+/// ties between equally common letters are broken.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ChecksumPolicy {
+    length: usize,
+    tie_break: TieBreak
+}
+
+impl ChecksumPolicy {
+    /// Creates a new checksum policy.
+    pub fn new(length: usize, tie_break: TieBreak) -> Self {
+        Self {
+            length: length,
+            tie_break: tie_break
         }
-        // Map counts to characters with those counts
-        let mut counts: HashMap<u64, Vec<char>> = HashMap::new();
-        for (k, v) in char_counts.iter() {
-            if counts.contains_key(&v) {
-                counts.get_mut(&v).unwrap().push(*k);
-            } else {
-                counts.insert(*v, vec![*k]);
+    }
+
+    /// Creates the policy specified in AOC 2016 Day 4 - the five most common letters, with ties
+    /// broken alphabetically.
+    pub fn standard() -> Self {
+        return ChecksumPolicy::new(5, TieBreak::Alphabetical);
+    }
+
+    /// Gets the number of letters in the checksum.
+    pub fn get_length(&self) -> usize {
+        return self.length;
+    }
+
+    /// Gets the method used to break ties between equally common letters.
+    pub fn get_tie_break(&self) -> TieBreak {
+        return self.tie_break;
+    }
+}
+
+impl fmt::Display for RoomData {
+    /// Formats the room as a line of puzzle input.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}-{}[{}]", self.encrypted_name, self.sector_id, self.checksum);
+    }
+}
+
+/// Represents the ways a generated decoy room can have its listed checksum corrupted.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DecoyKind {
+    /// The first two letters of the correct checksum are swapped.
+    SwappedLetters,
+    /// The last letter of the correct checksum is replaced with a letter not in the checksum.
+    ForeignLetter,
+}
+
+/// Generates a real room from a plaintext name (lowercase words separated by single spaces) and
+/// sector ID, with the encrypted name and correct checksum.
+pub fn generate_room(name: &str, sector_id: u64) -> Result<RoomData, AocError> {
+    let words_valid = name.split(' ').all(|word| !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()));
+    if !words_valid {
+        return Err(AocError::InvalidParameter(format!(
+            "room name {:?} must be lowercase words separated by single spaces",
+            name
+        )));
+    }
+    let mut room = RoomData::new(encrypt_name(name, sector_id), sector_id, String::new());
+    room.checksum = room.calculate_checksum();
+    return Ok(room);
+}
+
+/// Generates a decoy room from a plaintext name and sector ID - the same as a real room, except the
+/// listed checksum is corrupted in the given way so it never validates.
+pub fn generate_decoy(name: &str, sector_id: u64, kind: DecoyKind) -> Result<RoomData, AocError> {
+    let mut room = generate_room(name, sector_id)?;
+    let mut checksum = room.checksum.chars().collect::<Vec<char>>();
+    match kind {
+        DecoyKind::SwappedLetters => {
+            // Checksum letters are all different, so any swap changes the checksum
+            if checksum.len() < 2 {
+                return Err(AocError::InvalidParameter(format!(
+                    "room name {:?} has too few different letters to swap",
+                    name
+                )));
             }
+            checksum.swap(0, 1);
         }
-        // Determines top five most common characters from encrypted name, ties broken alphabetical
-        let mut calculated_checksum = String::new();
-        for (_count, chars) in counts.into_iter().sorted().rev() {
-            let chars = chars.into_iter().sorted().collect::<Vec<char>>();
-            for c in chars {
-                calculated_checksum.push(c);
-                if calculated_checksum.len() == 5 {
-                    break;
-                }
-            }
-            if calculated_checksum.len() == 5 {
-                break;
+        DecoyKind::ForeignLetter => {
+            // Checksum holds at most five letters, so a replacement is always available
+            let foreign = ('a'..='z').find(|c| !checksum.contains(c)).unwrap();
+            let last = checksum.len() - 1;
+            checksum[last] = foreign;
+        }
+    }
+    room.checksum = checksum.into_iter().collect::<String>();
+    return Ok(room);
+}
+
+/// Pattern matching the decrypted name of the room where North Pole objects are stored.
+pub const NORTH_POLE_PATTERN: &str = r"^northpole object storage$";
+
+/// Encrypts a plaintext room name - the inverse of `RoomData::decrypt_name`. Letters rotate
+/// backward by the sector ID and spaces encrypt to dashes.
+pub fn encrypt_name(name: &str, sector_id: u64) -> String {
+    let shift = (sector_id % 26) as i64;
+    return cipher::shift_text(&name.replace(' ', "-"), -shift);
+}
+
+/// Compiles a regular expression for matching decrypted room names.
+fn compile_name_pattern(pattern: &str) -> Result<Regex, AocError> {
+    match Regex::new(pattern) {
+        Ok(name_regex) => return Ok(name_regex),
+        Err(e) => return Err(AocError::InvalidParameter(format!("invalid room name pattern: {}", e))),
+    }
+}
+
+/// Finds every room with a decrypted name matching the given regular expression.
+pub fn find_rooms_matching<'a>(rooms: &'a [RoomData], pattern: &str) -> Result<Vec<&'a RoomData>, AocError> {
+    let name_regex = compile_name_pattern(pattern)?;
+    return Ok(rooms.iter().filter(|room| name_regex.is_match(&room.decrypt_name())).collect());
+}
+
+/// Represents a decoy room along with the checksum it should have listed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DecoyReport<'a> {
+    room: &'a RoomData,
+    expected_checksum: String
+}
+
+impl<'a> DecoyReport<'a> {
+    /// Gets the decoy room.
+    pub fn get_room(&self) -> &'a RoomData {
+        return self.room;
+    }
+
+    /// Gets the checksum listed for the decoy room.
+    pub fn get_listed_checksum(&self) -> &str {
+        return &self.room.checksum;
+    }
+
+    /// Gets the checksum calculated from the encrypted name of the decoy room.
+    pub fn get_expected_checksum(&self) -> &str {
+        return &self.expected_checksum;
+    }
+}
+
+/// Represents a collection of rooms, with the real rooms (those with a valid checksum) indexed by
+/// sector ID and decrypted name. Decoy rooms are kept but not indexed.
+pub struct RoomRegistry {
+    rooms: Vec<RoomData>,
+    decrypted_names: Vec<String>,
+    expected_checksums: Vec<String>,
+    real_indexes: Vec<usize>,
+    sector_index: HashMap<u64, Vec<usize>>,
+    name_index: HashMap<String, Vec<usize>>
+}
+
+impl RoomRegistry {
+    /// Creates a new registry from the given rooms, decrypting and indexing the real rooms.
+    pub fn new(rooms: Vec<RoomData>) -> Self {
+        let decrypted_names = rooms.iter().map(|room| room.decrypt_name()).collect::<Vec<String>>();
+        let expected_checksums = rooms.iter().map(|room| room.calculate_checksum()).collect::<Vec<String>>();
+        let mut real_indexes: Vec<usize> = vec![];
+        let mut sector_index: HashMap<u64, Vec<usize>> = HashMap::new();
+        let mut name_index: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, room) in rooms.iter().enumerate() {
+            if expected_checksums[i] != room.checksum {
+                continue;
             }
+            real_indexes.push(i);
+            sector_index.entry(room.sector_id).or_default().push(i);
+            name_index.entry(decrypted_names[i].clone()).or_default().push(i);
         }
-        return calculated_checksum;
+        return RoomRegistry {
+            rooms: rooms,
+            decrypted_names: decrypted_names,
+            expected_checksums: expected_checksums,
+            real_indexes: real_indexes,
+            sector_index: sector_index,
+            name_index: name_index
+        };
     }
 
-    /// Calculates checksum from encrypted name and checks if it matches the original checksum.
-    pub fn validate_checksum(&self) -> bool {
-        let calculated_checksum = self.calculate_checksum();
-        return calculated_checksum == self.checksum;
+    /// Gets every room in the registry, real and decoy, in their original order.
+    pub fn get_rooms(&self) -> &Vec<RoomData> {
+        return &self.rooms;
+    }
+
+    /// Gets the real rooms, in their original order.
+    pub fn get_real_rooms(&self) -> Vec<&RoomData> {
+        return self.collect_rooms(&self.real_indexes);
     }
+
+    /// Finds the real rooms with the given sector ID.
+    pub fn find_by_sector(&self, sector_id: u64) -> Vec<&RoomData> {
+        return self.sector_index.get(&sector_id).map_or(vec![], |indexes| self.collect_rooms(indexes));
+    }
+
+    /// Finds the real rooms with exactly the given decrypted name.
+    pub fn find_by_name(&self, name: &str) -> Vec<&RoomData> {
+        return self.name_index.get(name).map_or(vec![], |indexes| self.collect_rooms(indexes));
+    }
+
+    /// Finds the real rooms with a decrypted name containing the given text.
+    pub fn search_names(&self, text: &str) -> Vec<&RoomData> {
+        return self.search_names_by(|name| name.contains(text));
+    }
+
+    /// Finds the real rooms with a decrypted name matching the given regular expression.
+    pub fn search_names_regex(&self, pattern: &str) -> Result<Vec<&RoomData>, AocError> {
+        let name_regex = compile_name_pattern(pattern)?;
+        return Ok(self.search_names_by(|name| name_regex.is_match(name)));
+    }
+
+    /// Gets the decoy rooms, along with the checksum each should have listed.
+    pub fn get_decoys(&self) -> Vec<DecoyReport<'_>> {
+        return self
+            .rooms
+            .iter()
+            .zip(self.expected_checksums.iter())
+            .filter(|(room, expected)| room.checksum != **expected)
+            .map(|(room, expected)| DecoyReport {
+                room: room,
+                expected_checksum: expected.to_string()
+            })
+            .collect::<Vec<DecoyReport>>();
+    }
+
+    /// Exports every room as CSV, with a header row followed by one row per room.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("encrypted_name,sector_id,checksum,expected_checksum,real,decrypted_name\n");
+        for (i, room) in self.rooms.iter().enumerate() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                escape_csv_field(&room.encrypted_name),
+                room.sector_id,
+                escape_csv_field(&room.checksum),
+                escape_csv_field(&self.expected_checksums[i]),
+                self.expected_checksums[i] == room.checksum,
+                escape_csv_field(&self.decrypted_names[i])
+            ));
+        }
+        return csv;
+    }
+
+    /// Exports every room as a JSON array, with one object per room.
+    pub fn to_json(&self) -> String {
+        let rooms = self
+            .rooms
+            .iter()
+            .enumerate()
+            .map(|(i, room)| {
+                json!({
+                    "encrypted_name": room.encrypted_name,
+                    "sector_id": room.sector_id,
+                    "checksum": room.checksum,
+                    "expected_checksum": self.expected_checksums[i],
+                    "real": self.expected_checksums[i] == room.checksum,
+                    "decrypted_name": self.decrypted_names[i],
+                })
+            })
+            .collect::<Vec<Value>>();
+        return Value::Array(rooms).to_string();
+    }
+
+    /// Gets the rooms at the given indexes.
+    fn collect_rooms(&self, indexes: &[usize]) -> Vec<&RoomData> {
+        return indexes.iter().map(|i| &self.rooms[*i]).collect::<Vec<&RoomData>>();
+    }
+
+    /// Finds the real rooms with a decrypted name accepted by the given filter.
+    fn search_names_by<F: Fn(&str) -> bool>(&self, filter: F) -> Vec<&RoomData> {
+        return self
+            .real_indexes
+            .iter()
+            .filter(|i| filter(&self.decrypted_names[**i]))
+            .map(|i| &self.rooms[*i])
+            .collect::<Vec<&RoomData>>();
+    }
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break, doubling any quotes.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+    return field.to_string();
 }
 
 #[aoc_generator(day4)]
-fn generate_input(raw_input: &str) -> Vec<RoomData> {
+fn generate_input(raw_input: &str) -> Result<Vec<RoomData>, AocError> {
     let room_data_regex = Regex::new(r"^(.*)-(\d+)\[(.*)\]$").unwrap();
     let mut rooms: Vec<RoomData> = vec![];
-    for line in raw_input.lines() {
+    for (line_index, raw_line) in raw_input.lines().enumerate() {
         // Trim leading and trailing whitespace, then ignore empty lines
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
+        let line_offset = raw_line.len() - raw_line.trim_start().len();
         // All input lines should be correctly formatted
-        if !room_data_regex.is_match(line) {
-            panic!("Day 4 - incorrect line format in raw input.");
-        }
+        let captures = match room_data_regex.captures(line) {
+            Some(captures) => captures,
+            None => return Err(AocError::parse_token(line_index, raw_line, line_offset, line, "incorrect room data format")),
+        };
         // Extract room data fields from input line
-        let captures = room_data_regex.captures(line).unwrap();
         let encrypted_name = captures[1].to_string();
-        let sector_id = captures[2].parse::<u64>().unwrap();
+        let sector_id = match captures[2].parse::<u64>() {
+            Ok(sector_id) => sector_id,
+            Err(_) => {
+                let token = captures.get(2).unwrap();
+                let offset = line_offset + token.start();
+                return Err(AocError::parse_token(line_index, raw_line, offset, token.as_str(), "sector ID out of range"));
+            }
+        };
         let checksum = captures[3].to_string();
         let room_data = RoomData::new(encrypted_name, sector_id, checksum);
         rooms.push(room_data);
     }
-    return rooms;
+    return Ok(rooms);
 }
 
 #[aoc(day4, part1)]
@@ -136,31 +396,185 @@ fn solve_part_1(input: &Vec<RoomData>) -> u64 {
 }
 
 #[aoc(day4, part2)]
-fn solve_part_2(input: &Vec<RoomData>) -> u64 {
-    for room in input {
-        if room.decrypt_name() == "northpole object storage" {
-            return room.get_sector_id();
+fn solve_part_2(input: &Vec<RoomData>) -> Result<u64, AocError> {
+    let registry = RoomRegistry::new(input.clone());
+    match registry.search_names_regex(NORTH_POLE_PATTERN)?.first() {
+        Some(room) => return Ok(room.get_sector_id()),
+        None => {
+            return Err(AocError::Unsolvable(String::from(
+                "exhausted rooms without finding North Pole object storage",
+            )));
         }
     }
-    panic!("D4_P2: exhausted rooms without finding North Pole object storage!");
+}
+
+/// Solution for AOC 2016 Day 4, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<RoomData>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn get_day(&self) -> u32 {
+        return 4;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return generate_input(raw_input);
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return solve_part_2(input);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs::*;
+    use crate::utils::rng::XorShift;
 
     #[test]
     fn test_d04_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day4.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day4.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!(173787, result);
     }
 
     #[test]
     fn test_d04_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day4.txt").unwrap());
-        let result = solve_part_2(&input);
+        let input = generate_input(&read_to_string("./input/2016/day4.txt").unwrap()).unwrap();
+        let result = solve_part_2(&input).unwrap();
         assert_eq!(548, result);
     }
+
+    #[test]
+    fn test_d04_invalid_input() {
+        let error = generate_input("aaaaa-bbb-z-y-x-123[abxyz]\nnot-a-room[abcde]\n").err().unwrap();
+        assert_eq!(
+            AocError::Parse {
+                line: 2,
+                column: 1,
+                text: String::from("not-a-room[abcde]"),
+                reason: String::from("incorrect room data format"),
+            },
+            error
+        );
+    }
+
+    #[test]
+    fn test_d04_encrypt_name() {
+        let room = RoomData::new(String::from("qzmt-zixmtkozy-ivhz"), 343, String::new());
+        assert_eq!("very encrypted name", room.decrypt_name());
+        assert_eq!("qzmt-zixmtkozy-ivhz", encrypt_name("very encrypted name", 343));
+    }
+
+    #[test]
+    fn test_d04_find_rooms_matching() {
+        let input = generate_input(&read_to_string("./input/2016/day4.txt").unwrap()).unwrap();
+        let rooms = find_rooms_matching(&input, r"^northpole").unwrap();
+        assert_eq!(1, rooms.len());
+        // Cracking the name without the sector ID finds the same plaintext
+        let dictionary = cipher::Dictionary::common_english();
+        let candidates = cipher::crack(&rooms[0].get_encrypted_name().replace('-', " "), &dictionary);
+        assert_eq!("northpole object storage", candidates[0].get_plaintext());
+        assert_eq!((26 - rooms[0].get_sector_id() % 26) % 26, candidates[0].get_key());
+        assert!(find_rooms_matching(&input, "(unclosed").is_err());
+    }
+
+    #[test]
+    fn test_d04_generate_room() {
+        let room = generate_room("very encrypted name", 343).unwrap();
+        assert_eq!("qzmt-zixmtkozy-ivhz-343[zimth]", room.to_string());
+        assert!(room.validate_checksum());
+        let decoy = generate_decoy("very encrypted name", 343, DecoyKind::SwappedLetters).unwrap();
+        assert_eq!("qzmt-zixmtkozy-ivhz-343[izmth]", decoy.to_string());
+        let decoy = generate_decoy("very encrypted name", 343, DecoyKind::ForeignLetter).unwrap();
+        assert_eq!("qzmt-zixmtkozy-ivhz-343[zimta]", decoy.to_string());
+        assert!(generate_room("Not Lowercase", 1).is_err());
+        assert!(generate_room("double  space", 1).is_err());
+        assert!(generate_decoy("aaa a", 1, DecoyKind::SwappedLetters).is_err());
+    }
+
+    #[test]
+    fn test_d04_generated_rooms_round_trip() {
+        // Deterministic pseudo-random names with few distinct letters, so checksum ties are common
+        let mut rng = XorShift::new(0x0004_2016);
+        let mut next_value = |limit: u64| rng.next_below(limit);
+        for _ in 0..1000 {
+            let words = (0..1 + next_value(4))
+                .map(|_| {
+                    (0..1 + next_value(8))
+                        .map(|_| (b'a' + next_value(8) as u8) as char)
+                        .collect::<String>()
+                })
+                .collect::<Vec<String>>();
+            let name = words.join(" ");
+            let sector_id = next_value(1000);
+            let room = generate_room(&name, sector_id).unwrap();
+            assert!(room.validate_checksum());
+            assert_eq!(name, room.decrypt_name());
+            assert_eq!(vec![room.clone()], generate_input(&room.to_string()).unwrap());
+            let decoy = generate_decoy(&name, sector_id, DecoyKind::ForeignLetter).unwrap();
+            assert!(!decoy.validate_checksum());
+            assert_eq!(name, decoy.decrypt_name());
+            if let Ok(decoy) = generate_decoy(&name, sector_id, DecoyKind::SwappedLetters) {
+                assert!(!decoy.validate_checksum());
+            }
+        }
+    }
+
+    #[test]
+    fn test_d04_checksum_policy() {
+        let room = generate_input("not-a-real-room-404[oarel]").unwrap().remove(0);
+        assert!(room.validate_checksum());
+        assert_eq!("oar", room.calculate_checksum_with(&ChecksumPolicy::new(3, TieBreak::Alphabetical)));
+        assert_eq!("oratn", room.calculate_checksum_with(&ChecksumPolicy::new(5, TieBreak::ReverseAlphabetical)));
+        assert_eq!("oarnt", room.calculate_checksum_with(&ChecksumPolicy::new(5, TieBreak::FirstOccurrence)));
+        assert!(!room.validate_checksum_with(&ChecksumPolicy::new(4, TieBreak::Alphabetical)));
+    }
+
+    #[test]
+    fn test_d04_room_registry_proper() {
+        let input = generate_input(&read_to_string("./input/2016/day4.txt").unwrap()).unwrap();
+        let registry = RoomRegistry::new(input.clone());
+        let real_rooms = registry.get_real_rooms();
+        assert_eq!(173787, real_rooms.iter().map(|room| room.get_sector_id()).sum::<u64>());
+        assert_eq!(input.len(), real_rooms.len() + registry.get_decoys().len());
+        let storage = registry.find_by_name("northpole object storage");
+        assert_eq!(1, storage.len());
+        assert_eq!(548, storage[0].get_sector_id());
+        assert_eq!(storage, registry.find_by_sector(548));
+        assert_eq!(storage, registry.search_names("northpole"));
+        assert!(registry.find_by_sector(0).is_empty());
+        for decoy in registry.get_decoys() {
+            assert_ne!(decoy.get_listed_checksum(), decoy.get_expected_checksum());
+            assert!(registry.find_by_sector(decoy.get_room().get_sector_id()).iter().all(|room| room.validate_checksum()));
+        }
+    }
+
+    #[test]
+    fn test_d04_room_registry_export() {
+        let input = generate_input("aaaaa-bbb-z-y-x-123[abxyz]\nnot-a-real-room-404[oarel]\ntotally-real-room-200[decoy]\n").unwrap();
+        let registry = RoomRegistry::new(input);
+        assert_eq!(2, registry.search_names_regex(r"^\w+ \w+").unwrap().len());
+        let decoys = registry.get_decoys();
+        assert_eq!(1, decoys.len());
+        assert_eq!("decoy", decoys[0].get_listed_checksum());
+        assert_eq!("loart", decoys[0].get_expected_checksum());
+        let csv = registry.to_csv();
+        assert_eq!(4, csv.lines().count());
+        assert_eq!("totally-real-room,200,decoy,loart,false,lglsddq jwsd jgge", csv.lines().last().unwrap());
+        let json: Value = serde_json::from_str(&registry.to_json()).unwrap();
+        assert_eq!(3, json.as_array().unwrap().len());
+        assert_eq!(json!(404), json[1]["sector_id"]);
+        assert_eq!(json!(true), json[1]["real"]);
+        assert_eq!(json!("loart"), json[2]["expected_checksum"]);
+        assert_eq!("\"a,\"\"b\"", escape_csv_field("a,\"b"));
+    }
 }
@@ -1,65 +1,187 @@
-use std::collections::HashMap;
-use md5;
+use std::io::{self, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use super::solution::Solution;
+use super::utils::error::AocError;
+use super::utils::hashmine::{get_hex_digit, HashMiner};
+use super::utils::rng::XorShift;
 
 #[aoc_generator(day5)]
 fn generate_input(raw_input: &str) -> String {
     return raw_input.trim().to_string();
 }
 
+/// Number of characters in a door password.
+const PASSWORD_LEN: usize = 8;
+
+/// Number of leading zeroes in the hexadecimal form of a password hash.
+const PASSWORD_HASH_ZEROES: usize = 5;
+
 #[aoc(day5, part1)]
 fn solve_part_1(door_id: &String) -> String {
-    let mut index = 0;
-    let mut password = String::new();
-    // Keep processing until full eight-character password is determined
-    while password.len() < 8 {
-        // Keep looping until a password character is found
-        loop {
-            let hash_target = format!("{}{}", door_id, index.to_string());
-            index += 1;
-            let md5_digest = md5::compute(hash_target.as_bytes());
-            let md5_hash = format!("{:x}", md5_digest);
-            if md5_hash.starts_with("00000") {
-                password.push_str(&md5_hash[5..6]);
+    // Each password hash gives the next character in its sixth hex digit
+    return HashMiner::with_leading_zeroes(door_id, PASSWORD_HASH_ZEROES)
+        .take(PASSWORD_LEN)
+        .map(|(_, digest)| char::from_digit(get_hex_digit(&digest, 5) as u32, 16).unwrap())
+        .collect::<String>();
+}
+
+#[aoc(day5, part2)]
+fn solve_part_2(door_id: &String) -> String {
+    let miner = HashMiner::with_leading_zeroes(door_id, PASSWORD_HASH_ZEROES);
+    // The handler never stops the search, so the password is always found
+    return find_positional_password(miner, |_, _, _| true).unwrap();
+}
+
+/// Finds the Part 2 password from the hashes yielded by the miner, reporting each character to
+/// the handler (with the index of its hash and its position) as soon as it is found. The search
+/// continues for as long as the handler returns true - returns None if the handler stopped it.
+fn find_positional_password<F: FnMut(u64, usize, char) -> bool>(miner: HashMiner, mut on_found: F) -> Option<String> {
+    let mut password_chars: [Option<char>; PASSWORD_LEN] = [None; PASSWORD_LEN];
+    // Each password hash gives a position in its sixth hex digit and a character in its seventh
+    for (index, digest) in miner {
+        let position = get_hex_digit(&digest, 5) as usize;
+        // Only the first character found for each valid position is used
+        if position < PASSWORD_LEN && password_chars[position].is_none() {
+            let character = char::from_digit(get_hex_digit(&digest, 6) as u32, 16).unwrap();
+            password_chars[position] = Some(character);
+            if !on_found(index, position, character) {
+                return None;
+            }
+            if password_chars.iter().all(|c| c.is_some()) {
                 break;
             }
         }
     }
-    return password;
+    return Some(password_chars.iter().map(|c| c.unwrap()).collect::<String>());
 }
 
-#[aoc(day5, part2)]
-fn solve_part_2(door_id: &String) -> String {
-    let mut index = 0;
-    let mut password_chars: HashMap<usize, String> = HashMap::new();
-    // Keep processing until full eight-character password is found
-    while password_chars.len() < 8 {
-        // Keep looping until a password character is found
-        loop {
-            let hash_target = format!("{}{}", door_id, index);
-            index += 1;
-            let md5_digest = md5::compute(hash_target.as_bytes());
-            let md5_hash = format!("{:x}", md5_digest);
-            if md5_hash.starts_with("00000") {
-                // Check if the position is valid and not seen yet
-                let position = md5_hash[5..6].to_string();
-                if position.parse::<usize>().is_ok() {
-                    let position = position.parse::<usize>().unwrap();
-                    if !password_chars.contains_key(&position) && position < 8 {
-                        // Record the new password character
-                        let character = md5_hash[6..7].to_string();
-                        password_chars.insert(position, character);
-                    }
-                }
-                break;
+/// Delay between frames of the cinematic decryption animation.
+const CINEMATIC_FRAME_DELAY: Duration = Duration::from_millis(40);
+
+/// Represents a password character found by the search running behind the cinematic animation.
+struct FoundCharacter {
+    index: u64,
+    position: usize,
+    character: char
+}
+
+/// Renders one frame of the animation on the current terminal line - found characters are shown
+/// in bold and unfilled slots flicker with random dimmed hex digits.
+fn render_password_frame(password_chars: &[Option<char>], flicker: &mut XorShift) -> String {
+    let mut frame = String::from("\r\x1b[2KDecrypting password: ");
+    for slot in password_chars {
+        match slot {
+            Some(c) => frame.push_str(&format!("\x1b[1m{}\x1b[0m", c)),
+            None => {
+                let digit = char::from_digit(flicker.next_below(16) as u32, 16).unwrap();
+                frame.push_str(&format!("\x1b[2m{}\x1b[0m", digit));
+            }
+        }
+    }
+    return frame;
+}
+
+/// Finds the Part 2 password for the door ID "hacker movie" style, as requested by the puzzle. If
+/// animating (e.g. when the output is a terminal), the password is drawn in place with characters
+/// filled in as they are found and random hex digits flickering in the unfilled slots. Otherwise
+/// a plain progress line is written for each character found. Returns the password.
+pub fn decrypt_cinematic<W: Write>(door_id: &str, out: &mut W, animate: bool) -> io::Result<String> {
+    let miner = HashMiner::with_leading_zeroes(door_id, PASSWORD_HASH_ZEROES);
+    return play_password_search(miner, out, animate);
+}
+
+/// Runs the password search over the miner in a background thread, writing the animation or
+/// progress lines from the characters it sends back. If writing fails, the search is stopped and
+/// its thread joined before the error is returned.
+fn play_password_search<W: Write>(miner: HashMiner, out: &mut W, animate: bool) -> io::Result<String> {
+    let (sender, receiver) = mpsc::channel::<FoundCharacter>();
+    let searcher = thread::spawn(move || {
+        return find_positional_password(miner, |index, position, character| {
+            // Receiver only goes away if writing the output failed, so stop searching
+            return sender
+                .send(FoundCharacter {
+                    index: index,
+                    position: position,
+                    character: character
+                })
+                .is_ok();
+        });
+    });
+    let shown = show_found_characters(&receiver, out, animate);
+    // Drop the receiver so the searcher stops at its next character if the output failed
+    drop(receiver);
+    let password = searcher.join().unwrap();
+    shown?;
+    // The searcher only stops early once the receiver is dropped, which is after an error
+    let password = password.ok_or_else(|| io::Error::other("password search stopped early"))?;
+    if animate {
+        writeln!(out, "\r\x1b[2KPassword: {}", password)?;
+    } else {
+        writeln!(out, "Password: {}", password)?;
+    }
+    return Ok(password);
+}
+
+/// Writes the animation or progress lines for the characters received from the search, until the
+/// search finishes.
+fn show_found_characters<W: Write>(receiver: &Receiver<FoundCharacter>, out: &mut W, animate: bool) -> io::Result<()> {
+    let mut password_chars: [Option<char>; PASSWORD_LEN] = [None; PASSWORD_LEN];
+    let mut flicker = XorShift::new(0x2016);
+    loop {
+        let found = if animate {
+            match receiver.recv_timeout(CINEMATIC_FRAME_DELAY) {
+                Ok(found) => Some(found),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        } else {
+            match receiver.recv() {
+                Ok(found) => Some(found),
+                Err(_) => return Ok(()),
+            }
+        };
+        if let Some(found) = found {
+            password_chars[found.position] = Some(found.character);
+            if !animate {
+                writeln!(
+                    out,
+                    "Found '{}' at position {} (index {})",
+                    found.character, found.position, found.index
+                )?;
             }
         }
+        if animate {
+            write!(out, "{}", render_password_frame(&password_chars, &mut flicker))?;
+            out.flush()?;
+        }
+    }
+}
+
+/// Solution for AOC 2016 Day 5, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = String;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn get_day(&self) -> u32 {
+        return 5;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return Ok(generate_input(raw_input));
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
     }
-    // Reconstruct the password from characters at recorded positions
-    let mut password = String::new();
-    for i in 0..=7 {
-        password.push_str(password_chars.get(&i).unwrap());
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return Ok(solve_part_2(input));
     }
-    return password;
 }
 
 #[cfg(test)]
@@ -80,4 +202,56 @@ mod tests {
         let result = solve_part_2(&input);
         assert_eq!("999828ec", result);
     }
+
+    #[test]
+    fn test_d05_cinematic_plain() {
+        // Easier difficulty than the real puzzle, so the search is quick
+        let expected = find_positional_password(HashMiner::with_leading_zeroes("abc", 2), |_, _, _| true).unwrap();
+        let mut output: Vec<u8> = vec![];
+        let password = play_password_search(HashMiner::with_leading_zeroes("abc", 2), &mut output, false).unwrap();
+        assert_eq!(expected, password);
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<&str>>();
+        assert_eq!(PASSWORD_LEN + 1, lines.len());
+        assert!(lines[0].starts_with("Found '"));
+        assert_eq!(format!("Password: {}", expected), lines[PASSWORD_LEN]);
+    }
+
+    #[test]
+    fn test_d05_cinematic_animated() {
+        let mut output: Vec<u8> = vec![];
+        let password = play_password_search(HashMiner::with_leading_zeroes("abc", 2), &mut output, true).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        // One frame per character found at the very least, then the final password
+        assert!(output.matches("Decrypting password: ").count() >= PASSWORD_LEN);
+        assert!(output.ends_with(&format!("\r\x1b[2KPassword: {}\n", password)));
+        let mut flicker = XorShift::new(1);
+        let frame = render_password_frame(&[Some('a'), None], &mut flicker);
+        assert!(frame.starts_with("\r\x1b[2KDecrypting password: \x1b[1ma\x1b[0m\x1b[2m"));
+    }
+
+    /// Writer that always fails, standing in for a closed terminal.
+    struct FailingWriter;
+
+    impl Write for FailingWriter {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            return Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"));
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            return Ok(());
+        }
+    }
+
+    #[test]
+    fn test_d05_cinematic_write_error() {
+        let miner = HashMiner::with_leading_zeroes("abc", 2);
+        assert_eq!(None, find_positional_password(miner, |_, _, _| false));
+        // The error is returned once the searcher has stopped, rather than leaving it running
+        for animate in [false, true] {
+            let miner = HashMiner::with_leading_zeroes("abc", 2);
+            let error = play_password_search(miner, &mut FailingWriter, animate).err().unwrap();
+            assert_eq!(io::ErrorKind::BrokenPipe, error.kind());
+        }
+    }
 }
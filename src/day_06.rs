@@ -1,68 +1,77 @@
-use std::collections::HashMap;
+use super::solution::Solution;
+use super::utils::error::AocError;
+use super::utils::frequency::{FrequencyCounter, TieBreak};
 
 #[aoc_generator(day6)]
-fn generate_input(raw_input: &str) -> Vec<Vec<char>> {
+fn generate_input(raw_input: &str) -> Result<Vec<Vec<char>>, AocError> {
     let mut messages: Vec<Vec<char>> = vec![];
-    for line in raw_input.lines() {
-        let line = line.trim();
+    for (line_index, raw_line) in raw_input.lines().enumerate() {
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
-        messages.push(line.chars().collect::<Vec<char>>());
+        let message = line.chars().collect::<Vec<char>>();
+        // All messages must be the same length so every character position can be compared
+        if let Some(first) = messages.first() {
+            if message.len() != first.len() {
+                let line_offset = raw_line.len() - raw_line.trim_start().len();
+                return Err(AocError::parse_token(line_index, raw_line, line_offset, line, "message length differs from first message"));
+            }
+        }
+        messages.push(message);
     }
-    return messages;
+    return Ok(messages);
+}
+
+/// Counts the characters appearing at each position across all messages.
+fn count_positions(input: &Vec<Vec<char>>) -> Vec<FrequencyCounter<char>> {
+    let message_len = input.first().map_or(0, |message| message.len());
+    return (0..message_len)
+        .map(|i| FrequencyCounter::from_items(input.iter().map(|message| message[i])))
+        .collect::<Vec<FrequencyCounter<char>>>();
 }
 
 #[aoc(day6, part1)]
 fn solve_part_1(input: &Vec<Vec<char>>) -> String {
-    // Initialise the position counts
-    let mut position_counts: Vec<HashMap<char, u64>> = vec![];
-    for _ in 0..8 {
-        position_counts.push(HashMap::new());
-    }
-    // Determine count for each letter in each character index
-    for message in input {
-        for i in 0..8 {
-            if position_counts[i].contains_key(&message[i]) {
-                *position_counts[i].get_mut(&message[i]).unwrap() += 1;
-            } else {
-                position_counts[i].insert(message[i], 1);
-            }
-        }
-    }
-    // Determine most frequent character for each position
-    let mut decoded_message = String::new();
-    for i in 0..8 {
-        let top_char = *position_counts[i].iter().max_by_key(|entry| entry.1).unwrap().0;
-        decoded_message.push(top_char);
-    }
-    return decoded_message;
+    // Determine most frequent character for each position, with ties broken alphabetically
+    return count_positions(input)
+        .iter()
+        .map(|counter| counter.most_common(TieBreak::Alphabetical).unwrap())
+        .collect::<String>();
 }
 
 #[aoc(day6, part2)]
 fn solve_part_2(input: &Vec<Vec<char>>) -> String {
-    // Initialise the position counts
-    let mut position_counts: Vec<HashMap<char, u64>> = vec![];
-    for _ in 0..8 {
-        position_counts.push(HashMap::new());
+    // Determine least frequent character for each position, with ties broken alphabetically
+    return count_positions(input)
+        .iter()
+        .map(|counter| counter.least_common(TieBreak::Alphabetical).unwrap())
+        .collect::<String>();
+}
+
+/// Solution for AOC 2016 Day 6, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn get_day(&self) -> u32 {
+        return 6;
     }
-    // Determine count for each letter in each character index
-    for message in input {
-        for i in 0..8 {
-            if position_counts[i].contains_key(&message[i]) {
-                *position_counts[i].get_mut(&message[i]).unwrap() += 1;
-            } else {
-                position_counts[i].insert(message[i], 1);
-            }
-        }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return generate_input(raw_input);
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
     }
-    // Determine least frequent character for each position
-    let mut decoded_message = String::new();
-    for i in 0..8 {
-        let bot_char = *position_counts[i].iter().min_by_key(|entry| entry.1).unwrap().0;
-        decoded_message.push(bot_char);
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return Ok(solve_part_2(input));
     }
-    return decoded_message;
 }
 
 #[cfg(test)]
@@ -72,14 +81,14 @@ mod tests {
 
     #[test]
     fn test_d06_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day6.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day6.txt").unwrap()).unwrap();
         let result = solve_part_1(&input);
         assert_eq!("dzqckwsd", result);
     }
 
     #[test]
     fn test_d06_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day6.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day6.txt").unwrap()).unwrap();
         let result = solve_part_2(&input);
         assert_eq!("lragovly", result);
     }
@@ -1,4 +1,6 @@
 use std::collections::HashSet;
+use super::solution::Solution;
+use super::utils::error::AocError;
 
 #[aoc_generator(day7)]
 fn generate_input(raw_input: &str) -> Vec<Vec<char>> {
@@ -36,7 +38,7 @@ fn check_ip_addr_for_tls_support(ip_addr: &Vec<char>) -> bool {
         for n in range {
             if ip_addr[i + n] == '[' {
                 hypernet_depth += 1;
-                i = i + n;
+                i += n;
                 skip = true;
                 break;
             } else if ip_addr[i + n] == ']' {
@@ -45,7 +47,7 @@ fn check_ip_addr_for_tls_support(ip_addr: &Vec<char>) -> bool {
                     return false;
                 }
                 hypernet_depth -= 1;
-                i = i + n;
+                i += n;
                 skip = true;
                 break;
             }
@@ -104,7 +106,7 @@ fn check_ip_addr_for_ssl_support(ip_addr: &Vec<char>) -> bool {
         for n in range {
             if ip_addr[i + n] == '[' {
                 hypernet_depth += 1;
-                i = i + n;
+                i += n;
                 skip = true;
                 break;
             } else if ip_addr[i + n] == ']' {
@@ -113,7 +115,7 @@ fn check_ip_addr_for_ssl_support(ip_addr: &Vec<char>) -> bool {
                     return false;
                 }
                 hypernet_depth -= 1;
-                i = i + n;
+                i += n;
                 skip = true;
                 break;
             }
@@ -180,6 +182,31 @@ fn solve_part_2(input: &Vec<Vec<char>>) -> u64 {
     return count;
 }
 
+/// Solution for AOC 2016 Day 7, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn get_day(&self) -> u32 {
+        return 7;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return Ok(generate_input(raw_input));
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return Ok(solve_part_1(input));
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return Ok(solve_part_2(input));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
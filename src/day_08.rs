@@ -1,6 +1,10 @@
+use super::solution::Solution;
+use super::utils::carto::{Grid, Point2D};
+use super::utils::error::AocError;
 use regex::Regex;
 
-enum RotateType {
+/// Represents whether a rotate instruction shifts a row or a column of the screen.
+pub enum RotateType {
     Row,
     Column,
 }
@@ -15,7 +19,8 @@ impl RotateType {
     }
 }
 
-enum Instruction {
+/// Represents a single instruction for operating the screen.
+pub enum Instruction {
     Rect {
         x: usize,
         y: usize,
@@ -28,66 +33,71 @@ enum Instruction {
 }
 
 #[aoc_generator(day8)]
-fn generate_input(raw_input: &str) -> Vec<Instruction> {
+fn generate_input(raw_input: &str) -> Result<Vec<Instruction>, AocError> {
     let mut instructions: Vec<Instruction> = vec![];
     // Regex for matching the different instructions
     let rect_regex = Regex::new(r"^rect (\d+)x(\d+)$").unwrap();
     let rotate_regex = Regex::new(r"^rotate (row|column) (x|y)=(\d+) by (\d+)$").unwrap();
-    for line in raw_input.lines() {
+    for (line_index, raw_line) in raw_input.lines().enumerate() {
         // Trim whitespace from lines and ignore empty lines
-        let line = line.trim();
+        let line = raw_line.trim();
         if line.is_empty() {
             continue;
         }
+        let line_offset = raw_line.len() - raw_line.trim_start().len();
+        // Numeric fields are all matched as digits, so only fail when out of range
+        let parse_field = |field: regex::Match| {
+            return field.as_str().parse::<usize>().map_err(|_| {
+                AocError::parse_token(line_index, raw_line, line_offset + field.start(), field.as_str(), "value out of range")
+            });
+        };
         // Check for regex match
-        if rect_regex.is_match(line) {
-            let captures = rect_regex.captures(line).unwrap();
-            let x = captures[1].parse::<usize>().unwrap();
-            let y = captures[2].parse::<usize>().unwrap();
+        if let Some(captures) = rect_regex.captures(line) {
+            let x = parse_field(captures.get(1).unwrap())?;
+            let y = parse_field(captures.get(2).unwrap())?;
             instructions.push(Instruction::Rect { x, y });
-        } else if rotate_regex.is_match(line) {
-            let captures = rotate_regex.captures(line).unwrap();
+        } else if let Some(captures) = rotate_regex.captures(line) {
             // Regex ensures only valid rotation types are matched
             let rot_type = RotateType::from_string(&captures[1]).unwrap();
-            match rot_type {
-                RotateType::Row => {
-                    if &captures[2] != "y" {
-                        panic!("Day 8 - incorrect co-ordinate against row rotate type!");
-                    }
-                }
-                RotateType::Column => {
-                    if &captures[2] != "x" {
-                        panic!("Day 8 - incorrect co-ordinate against column rotate type!");
-                    }
-                }
+            let expected_axis = match rot_type {
+                RotateType::Row => "y",
+                RotateType::Column => "x",
             };
-            let vec_num = captures[3].parse::<usize>().unwrap();
-            let amount = captures[4].parse::<usize>().unwrap();
+            if &captures[2] != expected_axis {
+                let token = captures.get(2).unwrap();
+                let offset = line_offset + token.start();
+                return Err(AocError::parse_token(line_index, raw_line, offset, token.as_str(), "incorrect co-ordinate for rotate type"));
+            }
+            let vec_num = parse_field(captures.get(3).unwrap())?;
+            let amount = parse_field(captures.get(4).unwrap())?;
             instructions.push(Instruction::Rotate {
                 rot_type,
                 vec_num,
                 amount,
             });
         } else {
-            panic!("Day 8 - bad input line format!");
+            return Err(AocError::parse_token(line_index, raw_line, line_offset, line, "bad input line format"));
         }
     }
-    return instructions;
+    return Ok(instructions);
 }
 
 fn execute_instructions(
     instructions: &Vec<Instruction>,
     screen_width: usize,
     screen_height: usize,
-) -> Vec<Vec<bool>> {
-    // Initialise empty screen array - true is on, false is off
-    let mut screen_array = vec![vec![false; screen_width]; screen_height];
+) -> Result<Grid<bool>, AocError> {
+    // Initialise empty screen - true is on, false is off
+    let mut screen = Grid::new(screen_width, screen_height, false);
     for instruct in instructions {
         match instruct {
             Instruction::Rect { x, y } => {
+                if *x > screen_width || *y > screen_height {
+                    return Err(AocError::InvalidParameter(format!("rect {}x{} does not fit on the screen", x, y)));
+                }
                 for row in 0..*y {
                     for col in 0..*x {
-                        screen_array[row][col] = true;
+                        screen.set(&Point2D::new(col as i64, row as i64), true);
                     }
                 }
             }
@@ -95,63 +105,67 @@ fn execute_instructions(
                 rot_type,
                 vec_num,
                 amount,
-            } => {
-                for _ in 0..*amount {
-                    match rot_type {
-                        RotateType::Row => {
-                            let mut temp = screen_array[*vec_num][0];
-                            for i in 0..screen_width {
-                                let cursor = temp;
-                                temp = screen_array[*vec_num][(i + 1) % screen_width];
-                                screen_array[*vec_num][(i + 1) % screen_width] = cursor;
-                            }
-                        }
-                        RotateType::Column => {
-                            let mut temp = screen_array[0][*vec_num];
-                            for i in 0..screen_height {
-                                let cursor = temp;
-                                temp = screen_array[(i + 1) % screen_height][*vec_num];
-                                screen_array[(i + 1) % screen_height][*vec_num] = cursor;
-                            }
-                        }
+            } => match rot_type {
+                RotateType::Row => {
+                    if *vec_num >= screen_height {
+                        return Err(AocError::InvalidParameter(format!("row {} is off the screen", vec_num)));
                     }
+                    screen.rotate_row(*vec_num, *amount);
                 }
-            }
+                RotateType::Column => {
+                    if *vec_num >= screen_width {
+                        return Err(AocError::InvalidParameter(format!("column {} is off the screen", vec_num)));
+                    }
+                    screen.rotate_column(*vec_num, *amount);
+                }
+            },
         }
     }
-    return screen_array;
+    return Ok(screen);
 }
 
 #[aoc(day8, part1)]
-fn solve_part_1(instructions: &Vec<Instruction>) -> usize {
+fn solve_part_1(instructions: &Vec<Instruction>) -> Result<usize, AocError> {
     let screen_width = 50;
     let screen_height = 6;
-    let screen_array = execute_instructions(instructions, screen_width, screen_height);
-    return screen_array
-        .iter()
-        .flat_map(|row| row.iter())
-        .filter(|&x| *x == true)
-        .count();
+    let screen = execute_instructions(instructions, screen_width, screen_height)?;
+    return Ok(screen.iter().filter(|(_, &on)| on).count());
 }
 
 #[aoc(day8, part2)]
-fn solve_part_2(instructions: &Vec<Instruction>) -> String {
+fn solve_part_2(instructions: &Vec<Instruction>) -> Result<String, AocError> {
     let screen_width = 50;
     let screen_height = 6;
-    let screen_array = execute_instructions(instructions, screen_width, screen_height);
+    let screen = execute_instructions(instructions, screen_width, screen_height)?;
     let mut output = String::new();
     output.push('\n');
-    for y in 0..screen_height {
-        for x in 0..screen_width {
-            if screen_array[y][x] == true {
-                output.push('#');
-            } else {
-                output.push('.');
-            }
-        }
-        output.push('\n');
+    output.push_str(&screen.render(|&on| if on { '#' } else { '.' }));
+    return Ok(output);
+}
+
+/// Solution for AOC 2016 Day 8, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn get_day(&self) -> u32 {
+        return 8;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return generate_input(raw_input);
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return solve_part_1(input);
+    }
+
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return solve_part_2(input);
     }
-    return output;
 }
 
 #[cfg(test)]
@@ -161,14 +175,14 @@ mod tests {
 
     #[test]
     fn test_d08_p1_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day8.txt").unwrap());
-        let result = solve_part_1(&input);
+        let input = generate_input(&read_to_string("./input/2016/day8.txt").unwrap()).unwrap();
+        let result = solve_part_1(&input).unwrap();
         assert_eq!(123, result);
     }
 
     #[test]
     fn test_d08_p2_proper() {
-        let input = generate_input(&read_to_string("./input/2016/day8.txt").unwrap());
+        let input = generate_input(&read_to_string("./input/2016/day8.txt").unwrap()).unwrap();
         let answer = String::from("\n\
             .##..####.###..#..#.###..####.###....##.###...###.\n\
             #..#.#....#..#.#..#.#..#....#.#..#....#.#..#.#....\n\
@@ -176,7 +190,29 @@ mod tests {
             ####.#....#..#.#..#.###...#...#..#....#.###...##..\n\
             #..#.#....#..#.#..#.#....#....#..#.#..#.#.......#.\n\
             #..#.#....###...##..#....####.###...##..#....###..\n");
-        let result = solve_part_2(&input);
+        let result = solve_part_2(&input).unwrap();
         assert_eq!(answer, result);
     }
+
+    #[test]
+    fn test_d08_invalid_input() {
+        let error = generate_input("rect 3x2\nrotate row x=0 by 4\n").err().unwrap();
+        assert_eq!(
+            AocError::Parse {
+                line: 2,
+                column: 12,
+                text: String::from("x"),
+                reason: String::from("incorrect co-ordinate for rotate type"),
+            },
+            error
+        );
+        let input = generate_input("rotate column x=50 by 1").unwrap();
+        assert!(matches!(solve_part_1(&input), Err(AocError::InvalidParameter(_))));
+        // Oversized rects are rejected rather than clipped (or filled one cell at a time)
+        for rect in ["rect 100x3", "rect 3x7", "rect 1000000x1000000"] {
+            let input = generate_input(rect).unwrap();
+            assert!(matches!(solve_part_1(&input), Err(AocError::InvalidParameter(_))));
+        }
+        assert_eq!(300, solve_part_1(&generate_input("rect 50x6").unwrap()).unwrap());
+    }
 }
@@ -1,13 +1,20 @@
+use super::solution::Solution;
+use super::utils::error::AocError;
+
 #[aoc_generator(day9)]
 fn generate_input(raw_input: &str) -> String {
     // Remove all whitespace from the raw input
     return raw_input.chars().filter(|c| !c.is_whitespace()).collect::<String>();
 }
 
+/// Maximum number of characters permitted in the output of a single decompression pass.
+const MAX_DECOMPRESSED_LEN: usize = 100_000_000;
+
 /// Takes input compressed in experimental format (AOC 2016 Day 9) and conduct one pass of
 /// decompression. Note that to get the fully decompressed output, multiple decompression passes
-/// may be required.
-fn decompress_input(input: &String) -> String {
+/// may be required. Error positions refer to the input with whitespace removed. Returns an error if
+/// the output would exceed `MAX_DECOMPRESSED_LEN` characters.
+fn decompress_input(input: &String) -> Result<String, AocError> {
     let mut i = 0;
     let chars = input.chars().collect::<Vec<char>>();
     let mut decompressed = String::new();
@@ -18,14 +25,15 @@ fn decompress_input(input: &String) -> String {
         }
         // Check if current character is beginning of marker
         if chars[i] == '(' {
+            let marker_start = i;
             i += 1;
             let mut char_window_len = String::new();
             let mut num_repeats = String::new();
             let mut repeats_flag = false;
             loop {
-                // Panic if marker window goes past end of compressed string
+                // Marker window must not go past end of compressed string
                 if i >= chars.len() {
-                    panic!("D9_P1: marker window exceeded end of compressed string.");
+                    return Err(marker_error(&chars, marker_start, i, "marker exceeded end of compressed string"));
                 }
                 // Check for end of marker window
                 if chars[i] == ')' {
@@ -43,9 +51,27 @@ fn decompress_input(input: &String) -> String {
                     i += 1;
                 }
             }
-            // Try to convert lengths - panic if either are not convertable to usize
-            let char_window_len = char_window_len.parse::<usize>().unwrap();
-            let num_repeats = num_repeats.parse::<usize>().unwrap();
+            // Try to convert lengths - both must be convertable to usize
+            let (char_window_len, num_repeats) = match (char_window_len.parse::<usize>(), num_repeats.parse::<usize>()) {
+                (Ok(char_window_len), Ok(num_repeats)) => (char_window_len, num_repeats),
+                _ => return Err(marker_error(&chars, marker_start, i, "invalid marker lengths")),
+            };
+            // Repeated sequence must not go past end of compressed string (compared against the
+            // remaining length so huge window lengths cannot overflow)
+            if char_window_len > chars.len() - i {
+                return Err(marker_error(&chars, marker_start, i, "repeat sequence exceeded end of compressed string"));
+            }
+            // Check the expanded length before allocating, as markers can repeat almost endlessly
+            let within_limit = char_window_len
+                .checked_mul(num_repeats)
+                .and_then(|expanded_len| expanded_len.checked_add(decompressed.len()))
+                .is_some_and(|total_len| total_len <= MAX_DECOMPRESSED_LEN);
+            if !within_limit {
+                return Err(AocError::InvalidParameter(format!(
+                    "decompressed output exceeds {} characters",
+                    MAX_DECOMPRESSED_LEN
+                )));
+            }
             let mut repeat_sequence = String::new();
             // Observe the character sequence that is to be repeated
             for _ in 0..char_window_len {
@@ -62,32 +88,69 @@ fn decompress_input(input: &String) -> String {
             i += 1;
         }
     }
-    return decompressed;
+    return Ok(decompressed);
+}
+
+/// Creates a parse error for the marker spanning the given character indices.
+fn marker_error(chars: &[char], start: usize, end: usize, reason: &str) -> AocError {
+    return AocError::Parse {
+        line: 1,
+        column: start + 1,
+        text: chars[start..end.min(chars.len())].iter().collect::<String>(),
+        reason: reason.to_string(),
+    };
 }
 
 #[aoc(day9, part1)]
-fn solve_part_1(input: &String) -> usize {
-    let decompressed = decompress_input(input);
-    return decompressed.len();
+fn solve_part_1(input: &String) -> Result<usize, AocError> {
+    let decompressed = decompress_input(input)?;
+    return Ok(decompressed.len());
 }
 
 #[aoc(day9, part2)]
-fn solve_part_2(input: &String) -> usize {
+fn solve_part_2(input: &String) -> Result<usize, AocError> {
     let mut temp_input = input.to_string();
-    let mut num_passes = 0;
     loop {
         // Conduct one decompression pass
-        let temp_output = decompress_input(&temp_input);
+        let temp_output = decompress_input(&temp_input)?;
         // Check if the output is the same length as the input
         if temp_output.len() == temp_input.len() {
-            return temp_output.len();
+            return Ok(temp_output.len());
         }
-        num_passes += 1;
-        println!("No. passes: {} ||| Output len: {}", num_passes, temp_output.len());
         temp_input = temp_output;
     }
 }
 
+/// Solution for AOC 2016 Day 9, for use outside of cargo-aoc (see `solution::registry`).
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn get_day(&self) -> u32 {
+        return 9;
+    }
+
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError> {
+        return Ok(generate_input(raw_input));
+    }
+
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError> {
+        return solve_part_1(input);
+    }
+
+    fn part_2(&self, _input: &Self::Input) -> Result<Self::Answer2, AocError> {
+        return Err(AocError::InvalidParameter(String::from("day 9 part 2 is not yet solved")));
+    }
+
+    fn has_part_2(&self) -> bool {
+        // Current part 2 approach exhausts memory on real input, so is not offered for use
+        return false;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,7 +159,29 @@ mod tests {
     #[test]
     fn test_d09_p1_proper() {
         let input = generate_input(&read_to_string("./input/2016/day9.txt").unwrap());
-        let result = solve_part_1(&input);
+        let result = solve_part_1(&input).unwrap();
         assert_eq!(98135, result);
     }
+
+    #[test]
+    fn test_d09_invalid_markers() {
+        let error = solve_part_1(&generate_input("A(1x5)BC(3x3")).err().unwrap();
+        assert_eq!(
+            AocError::Parse {
+                line: 1,
+                column: 9,
+                text: String::from("(3x3"),
+                reason: String::from("marker exceeded end of compressed string"),
+            },
+            error
+        );
+        assert!(solve_part_1(&generate_input("(10x2)ABC")).is_err());
+        assert!(solve_part_1(&generate_input("(ax2)ABC")).is_err());
+        let error = solve_part_1(&generate_input("(18446744073709551615x2)A")).err().unwrap();
+        assert!(matches!(error, AocError::Parse { ref reason, .. } if reason == "repeat sequence exceeded end of compressed string"));
+        // Huge repeat counts are rejected before anything is allocated
+        for input in ["(1x18446744073709551615)A", "(1x100000001)A"] {
+            assert!(matches!(solve_part_1(&generate_input(input)), Err(AocError::InvalidParameter(_))));
+        }
+    }
 }
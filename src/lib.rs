@@ -1,7 +1,17 @@
+// Explicit returns, named struct fields and indexed loops are the preferred style in this crate
+#![allow(
+    clippy::needless_return,
+    clippy::redundant_field_names,
+    clippy::ptr_arg,
+    clippy::needless_range_loop
+)]
+
 #[macro_use]
 extern crate aoc_runner_derive;
 
+pub mod solution;
 pub mod utils;
+pub mod verify;
 
 // Modules for day solutions
 pub mod day_01;
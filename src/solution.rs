@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use super::utils::error::AocError;
+use super::{day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09};
+
+/// Represents the solution to a single day's puzzle - parsing the raw puzzle input and solving
+/// both parts of the puzzle from the parsed input.
+pub trait Solution {
+    /// Parsed form of the raw puzzle input, shared by both parts.
+    type Input;
+    /// Answer type for part 1.
+    type Answer1: Display;
+    /// Answer type for part 2.
+    type Answer2: Display;
+
+    /// Gets the day number of the puzzle (1 to 25).
+    fn get_day(&self) -> u32;
+
+    /// Parses the raw puzzle input.
+    fn parse(&self, raw_input: &str) -> Result<Self::Input, AocError>;
+
+    /// Solves part 1 of the puzzle.
+    fn part_1(&self, input: &Self::Input) -> Result<Self::Answer1, AocError>;
+
+    /// Solves part 2 of the puzzle.
+    fn part_2(&self, input: &Self::Input) -> Result<Self::Answer2, AocError>;
+
+    /// Checks if part 2 has a working solution. Solutions with an unsolved part 2 return an error
+    /// from part_2().
+    fn has_part_2(&self) -> bool {
+        return true;
+    }
+}
+
+/// Type-erased form of a Solution, allowing solutions with different input and answer types to be
+/// stored together in the registry. Implemented for every Solution.
+pub trait Puzzle {
+    /// Gets the day number of the puzzle (1 to 25).
+    fn get_day(&self) -> u32;
+
+    /// Checks if the given part (1 or 2) has a working solution.
+    fn has_part(&self, part: u32) -> bool;
+
+    /// Parses the raw input and solves the given part (1 or 2), returning the answer formatted for
+    /// display.
+    fn run_part(&self, part: u32, raw_input: &str) -> Result<String, AocError>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn get_day(&self) -> u32 {
+        return Solution::get_day(self);
+    }
+
+    fn has_part(&self, part: u32) -> bool {
+        match part {
+            1 => return true,
+            2 => return self.has_part_2(),
+            _ => return false,
+        }
+    }
+
+    fn run_part(&self, part: u32, raw_input: &str) -> Result<String, AocError> {
+        if !self.has_part(part) {
+            let day = Solution::get_day(self);
+            return Err(AocError::InvalidParameter(format!("day {} part {} is not implemented", day, part)));
+        }
+        let input = self.parse(raw_input)?;
+        match part {
+            1 => return Ok(self.part_1(&input)?.to_string()),
+            _ => return Ok(self.part_2(&input)?.to_string()),
+        }
+    }
+}
+
+/// Gets the solutions for every implemented day, keyed by day number.
+pub fn registry() -> BTreeMap<u32, Box<dyn Puzzle>> {
+    let puzzles: Vec<Box<dyn Puzzle>> = vec![
+        Box::new(day_01::Day01),
+        Box::new(day_02::Day02),
+        Box::new(day_03::Day03),
+        Box::new(day_04::Day04),
+        Box::new(day_05::Day05),
+        Box::new(day_06::Day06),
+        Box::new(day_07::Day07),
+        Box::new(day_08::Day08),
+        Box::new(day_09::Day09),
+    ];
+    return puzzles.into_iter().map(|puzzle| (puzzle.get_day(), puzzle)).collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_run_part() {
+        let registry = registry();
+        assert_eq!((1..=9).collect::<Vec<u32>>(), registry.keys().copied().collect::<Vec<u32>>());
+        assert_eq!(Ok(String::from("5")), registry[&1].run_part(1, "R2, L3"));
+        assert_eq!(Ok(String::from("1985")), registry[&2].run_part(1, "ULL\nRRDDD\nLURDL\nUUUUD\n"));
+    }
+
+    #[test]
+    fn test_registry_unavailable_parts() {
+        let registry = registry();
+        assert!(matches!(registry[&1].run_part(3, ""), Err(AocError::InvalidParameter(_))));
+        assert!(!registry[&9].has_part(2));
+        assert!(matches!(registry[&9].run_part(2, ""), Err(AocError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_solution_typed_answers() {
+        let input = day_01::Day01.parse("R5, L5, R5, R3").unwrap();
+        assert_eq!(12, day_01::Day01.part_1(&input).unwrap());
+    }
+}
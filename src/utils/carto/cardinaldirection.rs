@@ -1,5 +1,11 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::Vector2D;
+use crate::utils::error::AocError;
+
 /// Represents one of the four cardinal directions.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub enum CardinalDirection {
     North,
     East,
@@ -8,6 +14,16 @@ pub enum CardinalDirection {
 }
 
 impl CardinalDirection {
+    /// Gets all four cardinal directions in clockwise order, starting from North.
+    pub fn all() -> [CardinalDirection; 4] {
+        return [
+            CardinalDirection::North,
+            CardinalDirection::East,
+            CardinalDirection::South,
+            CardinalDirection::West,
+        ];
+    }
+
     /// Determines new direction resulting from single 90-degree rotation to left (CCW).
     pub fn rotate_left(&self) -> CardinalDirection {
         match self {
@@ -27,4 +43,84 @@ impl CardinalDirection {
             CardinalDirection::West => return CardinalDirection::North,
         }
     }
+
+    /// Determines new direction resulting from the given number of 90-degree rotations. Positive
+    /// values rotate to the right (CW) and negative values rotate to the left (CCW).
+    pub fn rotate_by(&self, quarter_turns: i64) -> CardinalDirection {
+        let index = CardinalDirection::all().iter().position(|dir| dir == self).unwrap() as i64;
+        return CardinalDirection::all()[(index + quarter_turns).rem_euclid(4) as usize];
+    }
+
+    /// Determines the direction facing the opposite way to the current direction.
+    pub fn opposite(&self) -> CardinalDirection {
+        return self.rotate_by(2);
+    }
+
+    /// Determines the unit vector for the direction. North is in the negative-y direction, with
+    /// the y-coordinate increasing downwards.
+    pub fn unit_vector(&self) -> Vector2D {
+        match self {
+            CardinalDirection::North => return Vector2D::new(0, -1),
+            CardinalDirection::East => return Vector2D::new(1, 0),
+            CardinalDirection::South => return Vector2D::new(0, 1),
+            CardinalDirection::West => return Vector2D::new(-1, 0),
+        }
+    }
+}
+
+impl FromStr for CardinalDirection {
+    type Err = AocError;
+
+    /// Parses a direction from compass letters (N/E/S/W) or screen letters (U/R/D/L).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "N" | "U" => return Ok(CardinalDirection::North),
+            "E" | "R" => return Ok(CardinalDirection::East),
+            "S" | "D" => return Ok(CardinalDirection::South),
+            "W" | "L" => return Ok(CardinalDirection::West),
+            _ => return Err(AocError::InvalidParameter(format!("invalid cardinal direction {:?}", s))),
+        }
+    }
+}
+
+impl fmt::Display for CardinalDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letter = match self {
+            CardinalDirection::North => "N",
+            CardinalDirection::East => "E",
+            CardinalDirection::South => "S",
+            CardinalDirection::West => "W",
+        };
+        return write!(f, "{}", letter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_and_opposite() {
+        let dir = CardinalDirection::North;
+        assert_eq!(dir.rotate_right(), dir.rotate_by(1));
+        assert_eq!(dir.rotate_left(), dir.rotate_by(-1));
+        assert_eq!(CardinalDirection::West, dir.rotate_by(-5));
+        assert_eq!(dir, dir.rotate_by(8));
+        for dir in CardinalDirection::all().iter() {
+            assert_eq!(-dir.unit_vector(), dir.opposite().unit_vector());
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(Ok(CardinalDirection::South), "D".parse::<CardinalDirection>());
+        assert_eq!(Ok(CardinalDirection::West), "W".parse::<CardinalDirection>());
+        assert_eq!(
+            Err(AocError::InvalidParameter(String::from("invalid cardinal direction \"X\""))),
+            "X".parse::<CardinalDirection>()
+        );
+        for dir in CardinalDirection::all().iter() {
+            assert_eq!(Ok(*dir), dir.to_string().parse::<CardinalDirection>());
+        }
+    }
 }
@@ -0,0 +1,123 @@
+use super::{CardinalDirection, Vector2D};
+
+/// Represents one of the eight principal compass directions - the four cardinal directions and the
+/// four ordinal (intercardinal) directions between them.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub enum CompassDirection {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest
+}
+
+impl CompassDirection {
+    /// Gets all eight compass directions in clockwise order, starting from North. This matches the
+    /// order of the points returned by `Point2D::get_surrounding_points`.
+    pub fn all() -> [CompassDirection; 8] {
+        return [
+            CompassDirection::North,
+            CompassDirection::NorthEast,
+            CompassDirection::East,
+            CompassDirection::SouthEast,
+            CompassDirection::South,
+            CompassDirection::SouthWest,
+            CompassDirection::West,
+            CompassDirection::NorthWest,
+        ];
+    }
+
+    /// Determines new direction resulting from the given number of 45-degree rotations. Positive
+    /// values rotate to the right (CW) and negative values rotate to the left (CCW).
+    pub fn rotate_by(&self, eighth_turns: i64) -> CompassDirection {
+        let index = CompassDirection::all().iter().position(|dir| dir == self).unwrap() as i64;
+        return CompassDirection::all()[(index + eighth_turns).rem_euclid(8) as usize];
+    }
+
+    /// Determines new direction resulting from single 45-degree rotation to left (CCW).
+    pub fn rotate_left(&self) -> CompassDirection {
+        return self.rotate_by(-1);
+    }
+
+    /// Determines new direction resulting from single 45-degree rotation to right (CW).
+    pub fn rotate_right(&self) -> CompassDirection {
+        return self.rotate_by(1);
+    }
+
+    /// Determines the direction facing the opposite way to the current direction.
+    pub fn opposite(&self) -> CompassDirection {
+        return self.rotate_by(4);
+    }
+
+    /// Checks if the direction is one of the four ordinal (diagonal) directions.
+    pub fn is_ordinal(&self) -> bool {
+        return self.to_cardinal().is_none();
+    }
+
+    /// Converts the direction to the equivalent cardinal direction, or None if it is diagonal.
+    pub fn to_cardinal(&self) -> Option<CardinalDirection> {
+        match self {
+            CompassDirection::North => return Some(CardinalDirection::North),
+            CompassDirection::East => return Some(CardinalDirection::East),
+            CompassDirection::South => return Some(CardinalDirection::South),
+            CompassDirection::West => return Some(CardinalDirection::West),
+            _ => return None,
+        }
+    }
+
+    /// Determines the unit vector for the direction. Diagonal directions have a component of
+    /// magnitude one along each axis, and the y-coordinate increases downwards.
+    pub fn unit_vector(&self) -> Vector2D {
+        match self {
+            CompassDirection::North => return Vector2D::new(0, -1),
+            CompassDirection::NorthEast => return Vector2D::new(1, -1),
+            CompassDirection::East => return Vector2D::new(1, 0),
+            CompassDirection::SouthEast => return Vector2D::new(1, 1),
+            CompassDirection::South => return Vector2D::new(0, 1),
+            CompassDirection::SouthWest => return Vector2D::new(-1, 1),
+            CompassDirection::West => return Vector2D::new(-1, 0),
+            CompassDirection::NorthWest => return Vector2D::new(-1, -1),
+        }
+    }
+}
+
+impl From<CardinalDirection> for CompassDirection {
+    fn from(dir: CardinalDirection) -> Self {
+        match dir {
+            CardinalDirection::North => return CompassDirection::North,
+            CardinalDirection::East => return CompassDirection::East,
+            CardinalDirection::South => return CompassDirection::South,
+            CardinalDirection::West => return CompassDirection::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compass_rotation() {
+        let dir = CompassDirection::North;
+        assert_eq!(CompassDirection::NorthEast, dir.rotate_right());
+        assert_eq!(CompassDirection::NorthWest, dir.rotate_left());
+        assert_eq!(CompassDirection::South, dir.rotate_by(-12));
+        for dir in CompassDirection::all().iter() {
+            assert_eq!(-dir.unit_vector(), dir.opposite().unit_vector());
+            assert_eq!(*dir, dir.rotate_by(8));
+        }
+    }
+
+    #[test]
+    fn test_compass_cardinal_conversion() {
+        for dir in CardinalDirection::all().iter() {
+            let compass = CompassDirection::from(*dir);
+            assert_eq!(Some(*dir), compass.to_cardinal());
+            assert_eq!(dir.unit_vector(), compass.unit_vector());
+        }
+        assert!(CompassDirection::SouthWest.is_ordinal());
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::Point2D;
+
+/// Represents a rectangular grid of values indexed by 2D points, with the origin (0, 0) located at
+/// the top-left corner. The x-coordinate increases to the right and the y-coordinate increases
+/// downwards.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a new grid of the given size with every cell set to the fill value.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width: width,
+            height: height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from the given rows of values. Returns None if the rows are not all the same
+    /// length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells: Vec<T> = Vec::with_capacity(width * height);
+        for row in rows {
+            if row.len() != width {
+                return None;
+            }
+            cells.extend(row);
+        }
+        return Some(Self {
+            width: width,
+            height: height,
+            cells: cells,
+        });
+    }
+
+    /// Gets the number of columns in the grid.
+    pub fn get_width(&self) -> usize {
+        return self.width;
+    }
+
+    /// Gets the number of rows in the grid.
+    pub fn get_height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Checks if the given location is within the bounds of the grid.
+    pub fn contains(&self, loc: &Point2D) -> bool {
+        return loc.get_x() >= 0
+            && loc.get_y() >= 0
+            && (loc.get_x() as u64) < self.width as u64
+            && (loc.get_y() as u64) < self.height as u64;
+    }
+
+    /// Gets a reference to the value at the given location, or None if it is out of bounds.
+    pub fn get(&self, loc: &Point2D) -> Option<&T> {
+        let index = self.get_cell_index(loc)?;
+        return Some(&self.cells[index]);
+    }
+
+    /// Gets a mutable reference to the value at the given location, or None if it is out of
+    /// bounds.
+    pub fn get_mut(&mut self, loc: &Point2D) -> Option<&mut T> {
+        let index = self.get_cell_index(loc)?;
+        return Some(&mut self.cells[index]);
+    }
+
+    /// Updates the value at the given location. Returns false if the location is out of bounds.
+    pub fn set(&mut self, loc: &Point2D, value: T) -> bool {
+        match self.get_mut(loc) {
+            Some(cell) => {
+                *cell = value;
+                return true;
+            }
+            None => return false,
+        }
+    }
+
+    /// Gets an iterator over the rows of the grid, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // Width of zero would cause chunks() to panic, but there are no cells to yield anyway
+        return self.cells.chunks(self.width.max(1));
+    }
+
+    /// Gets an iterator over the values in the specified row, from left to right. Panics if the
+    /// row is out of bounds.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(y < self.height, "Grid row {} out of bounds", y);
+        return self.cells[y * self.width..(y + 1) * self.width].iter();
+    }
+
+    /// Gets an iterator over the values in the specified column, from top to bottom. Panics if
+    /// the column is out of bounds.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Grid column {} out of bounds", x);
+        return self.cells.iter().skip(x).step_by(self.width);
+    }
+
+    /// Gets an iterator over every location in the grid, in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point2D> {
+        let width = self.width;
+        return (0..self.width * self.height)
+            .map(move |i| Point2D::new((i % width) as i64, (i / width) as i64));
+    }
+
+    /// Gets an iterator over every location in the grid and its value, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D, &T)> {
+        return self.points().zip(self.cells.iter());
+    }
+
+    /// Gets the in-bounds locations directly above, right of, below and left of the given
+    /// location (4-way neighbours).
+    pub fn get_orthogonal_neighbours(&self, loc: &Point2D) -> Vec<Point2D> {
+        return loc
+            .get_orthogonal_points()
+            .into_iter()
+            .filter(|p| self.contains(p))
+            .collect::<Vec<Point2D>>();
+    }
+
+    /// Gets the in-bounds locations surrounding the given location, including diagonals (8-way
+    /// neighbours).
+    pub fn get_surrounding_neighbours(&self, loc: &Point2D) -> Vec<Point2D> {
+        return loc
+            .get_surrounding_points()
+            .into_iter()
+            .filter(|p| self.contains(p))
+            .collect::<Vec<Point2D>>();
+    }
+
+    /// Rotates the values in the specified row to the right by the given amount, with values
+    /// shifted off the right edge wrapping around to the left edge.
+    pub fn rotate_row(&mut self, y: usize, amount: usize) {
+        assert!(y < self.height, "Grid row {} out of bounds", y);
+        if self.width == 0 {
+            return;
+        }
+        self.cells[y * self.width..(y + 1) * self.width].rotate_right(amount % self.width);
+    }
+
+    /// Rotates the values in the specified column downwards by the given amount, with values
+    /// shifted off the bottom edge wrapping around to the top edge.
+    pub fn rotate_column(&mut self, x: usize, amount: usize) {
+        assert!(x < self.width, "Grid column {} out of bounds", x);
+        if self.height == 0 {
+            return;
+        }
+        for _ in 0..(amount % self.height) {
+            for y in (1..self.height).rev() {
+                self.cells.swap(y * self.width + x, (y - 1) * self.width + x);
+            }
+        }
+    }
+
+    /// Creates a new grid of the same size by applying the given function to every value.
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Grid<U> {
+        return Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect::<Vec<U>>(),
+        };
+    }
+
+    /// Renders the grid as text, using the given function to select the character for each value.
+    /// Every row (including the last) is terminated with a newline.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows().take(self.height) {
+            output.extend(row.iter().map(&f));
+            output.push('\n');
+        }
+        return output;
+    }
+
+    /// Determines the index into the underlying cell storage for the given location.
+    fn get_cell_index(&self, loc: &Point2D) -> Option<usize> {
+        if !self.contains(loc) {
+            return None;
+        }
+        return Some(loc.get_y() as usize * self.width + loc.get_x() as usize);
+    }
+}
+
+impl Grid<char> {
+    /// Parses a block of text into a character grid, with each line forming one row. Empty lines
+    /// are ignored. Returns None if the remaining lines are not all the same length.
+    pub fn from_text(text: &str) -> Option<Self> {
+        let rows = text
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>();
+        return Grid::from_rows(rows);
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.render(|c| *c));
+    }
+}
+
+impl<T> Index<Point2D> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, loc: Point2D) -> &T {
+        match self.get(&loc) {
+            Some(value) => return value,
+            None => panic!("Grid location {:?} out of bounds", loc),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2D> for Grid<T> {
+    fn index_mut(&mut self, loc: Point2D) -> &mut T {
+        match self.get_mut(&loc) {
+            Some(value) => return value,
+            None => panic!("Grid location {:?} out of bounds", loc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_from_text_and_render() {
+        let text = "#..\n.#.\n..#\n";
+        let grid = Grid::from_text(text).unwrap();
+        assert_eq!(3, grid.get_width());
+        assert_eq!(3, grid.get_height());
+        assert_eq!('#', grid[Point2D::new(1, 1)]);
+        assert_eq!(text, grid.to_string());
+    }
+
+    #[test]
+    fn test_grid_from_text_ragged() {
+        assert!(Grid::from_text("ab\nc\n").is_none());
+    }
+
+    #[test]
+    fn test_grid_rows_and_columns() {
+        let grid = Grid::from_text("abc\ndef\n").unwrap();
+        assert_eq!(vec!['d', 'e', 'f'], grid.row(1).copied().collect::<Vec<char>>());
+        assert_eq!(vec!['c', 'f'], grid.column(2).copied().collect::<Vec<char>>());
+        assert_eq!(2, grid.rows().count());
+    }
+
+    #[test]
+    fn test_grid_bounds_and_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        assert!(grid.contains(&Point2D::new(2, 1)));
+        assert!(!grid.contains(&Point2D::new(3, 0)));
+        assert!(!grid.contains(&Point2D::new(0, -1)));
+        assert!(grid.get(&Point2D::new(-1, 0)).is_none());
+        let corner = Point2D::new(0, 0);
+        assert_eq!(
+            vec![Point2D::new(1, 0), Point2D::new(0, 1)],
+            grid.get_orthogonal_neighbours(&corner)
+        );
+        assert_eq!(3, grid.get_surrounding_neighbours(&corner).len());
+        assert_eq!(5, grid.get_surrounding_neighbours(&Point2D::new(1, 0)).len());
+    }
+
+    #[test]
+    fn test_grid_rotation() {
+        let mut grid = Grid::from_text("abc\ndef\n").unwrap();
+        grid.rotate_row(0, 4);
+        grid.rotate_column(2, 1);
+        assert_eq!("caf\ndeb\n", grid.to_string());
+    }
+}
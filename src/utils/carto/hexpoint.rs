@@ -0,0 +1,131 @@
+use std::convert::TryFrom;
+
+/// Represents a single hexagon on a hex grid, using axial co-ordinates (q, r). The implied third
+/// cube co-ordinate (s) is always equal to -q - r.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct HexPoint {
+    q: i64,
+    r: i64
+}
+
+impl HexPoint {
+    /// Creates a new hex point from axial co-ordinates.
+    pub fn new(q: i64, r: i64) -> Self {
+        Self {
+            q: q,
+            r: r
+        }
+    }
+
+    /// Creates a new hex point from cube co-ordinates. Returns None if the co-ordinates do not sum
+    /// to zero.
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Option<Self> {
+        // Sum in a wider type so extreme co-ordinates cannot overflow the check itself
+        if q as i128 + r as i128 + s as i128 != 0 {
+            return None;
+        }
+        return Some(HexPoint::new(q, r));
+    }
+
+    /// Gets the value of the q-coordinate.
+    pub fn get_q(&self) -> i64 {
+        return self.q;
+    }
+
+    /// Gets the value of the r-coordinate.
+    pub fn get_r(&self) -> i64 {
+        return self.r;
+    }
+
+    /// Gets the value of the implied s-coordinate from the cube representation. Panics if the value
+    /// does not fit in an i64.
+    pub fn get_s(&self) -> i64 {
+        return self.q.checked_neg()
+            .and_then(|neg_q| neg_q.checked_sub(self.r))
+            .expect("HexPoint s-coordinate overflowed");
+    }
+
+    /// Determines the resulting location if the current location was shifted by given deltas.
+    /// Panics if integer overflow or underflow would occur.
+    pub fn peek_point(&self, delta_q: i64, delta_r: i64) -> HexPoint {
+        let q = self.q.checked_add(delta_q).expect("HexPoint shift overflowed");
+        let r = self.r.checked_add(delta_r).expect("HexPoint shift overflowed");
+        return HexPoint::new(q, r);
+    }
+
+    /// Gets the six neighbouring hexes, in clockwise order starting from the neighbour in the
+    /// positive-q direction. Panics if integer overflow or underflow would occur.
+    pub fn get_neighbours(&self) -> Vec<HexPoint> {
+        return vec![
+            self.peek_point(1, 0),
+            self.peek_point(0, 1),
+            self.peek_point(-1, 1),
+            self.peek_point(-1, 0),
+            self.peek_point(0, -1),
+            self.peek_point(1, -1),
+        ];
+    }
+
+    /// Calculates the minimum number of steps between neighbouring hexes required to move from the
+    /// current hex to the other hex. Returns None if the distance does not fit in a u64.
+    pub fn checked_hex_distance(&self, other: &HexPoint) -> Option<u64> {
+        let dq = self.q.abs_diff(other.q);
+        let dr = self.r.abs_diff(other.r);
+        // The s-coordinates may not fit in an i64, so compare them in a wider type
+        let self_s = -(self.q as i128) - self.r as i128;
+        let other_s = -(other.q as i128) - other.r as i128;
+        let ds = u64::try_from(self_s.abs_diff(other_s)).ok()?;
+        return Some(dq.max(dr).max(ds));
+    }
+
+    /// Calculates the minimum number of steps between neighbouring hexes required to move from the
+    /// current hex to the other hex. Panics if the distance does not fit in a u64.
+    pub fn calculate_hex_distance(&self, other: &HexPoint) -> u64 {
+        return self.checked_hex_distance(other).expect("Hex distance overflowed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_neighbours_distance() {
+        let origin = HexPoint::new(0, 0);
+        let neighbours = origin.get_neighbours();
+        assert_eq!(6, neighbours.len());
+        for hex in neighbours {
+            assert_eq!(1, origin.calculate_hex_distance(&hex));
+        }
+    }
+
+    #[test]
+    fn test_hex_distance() {
+        let a = HexPoint::from_cube(1, -3, 2).unwrap();
+        let b = HexPoint::new(-2, 1);
+        assert_eq!(4, a.calculate_hex_distance(&b));
+        assert_eq!(4, b.calculate_hex_distance(&a));
+        assert!(HexPoint::from_cube(1, 1, 1).is_none());
+        assert!(HexPoint::from_cube(i64::MAX, i64::MAX, i64::MAX).is_none());
+        assert_eq!(Some(HexPoint::new(i64::MAX, i64::MIN + 1)), HexPoint::from_cube(i64::MAX, i64::MIN + 1, 0));
+    }
+
+    #[test]
+    fn test_hex_distance_extreme_values() {
+        let a = HexPoint::new(i64::MAX, 0);
+        let b = HexPoint::new(-1, 0);
+        assert_eq!(1 << 63, a.calculate_hex_distance(&b));
+        assert_eq!(1 << 63, b.calculate_hex_distance(&a));
+        let c = HexPoint::new(i64::MIN, 0);
+        assert_eq!(u64::MAX, c.calculate_hex_distance(&a));
+        let d = HexPoint::new(i64::MIN, i64::MIN);
+        let e = HexPoint::new(i64::MAX, i64::MAX);
+        assert_eq!(None, d.checked_hex_distance(&e));
+    }
+
+    #[test]
+    #[should_panic(expected = "HexPoint shift overflowed")]
+    fn test_hex_peek_point_overflow() {
+        HexPoint::new(i64::MAX, 0).peek_point(1, 0);
+    }
+}
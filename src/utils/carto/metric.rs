@@ -0,0 +1,44 @@
+/// Common distance calculations for points with integer co-ordinates along N axes.
+pub trait Metric<const N: usize> {
+    /// Gets the co-ordinates of the point, ordered by axis.
+    fn get_coordinates(&self) -> [i64; N];
+
+    /// Calculates the Manhattan (taxicab) distance between the current point and the other point.
+    /// Returns None if the distance does not fit in a u64.
+    fn checked_manhattan_distance(&self, other: &Self) -> Option<u64> {
+        return self
+            .get_coordinates()
+            .iter()
+            .zip(other.get_coordinates().iter())
+            .try_fold(0u64, |total, (a, b)| total.checked_add(a.abs_diff(*b)));
+    }
+
+    /// Calculates the Manhattan (taxicab) distance between the current point and the other point.
+    /// Panics if the distance does not fit in a u64.
+    fn calculate_manhattan_distance(&self, other: &Self) -> u64 {
+        return self.checked_manhattan_distance(other).expect("Manhattan distance overflowed");
+    }
+
+    /// Calculates the Chebyshev (chessboard) distance between the current point and the other
+    /// point.
+    fn calculate_chebyshev_distance(&self, other: &Self) -> u64 {
+        return self
+            .get_coordinates()
+            .iter()
+            .zip(other.get_coordinates().iter())
+            .map(|(a, b)| a.abs_diff(*b))
+            .max()
+            .unwrap_or(0);
+    }
+
+    /// Calculates the square of the Euclidean (straight-line) distance between the current point
+    /// and the other point. The square is used so the result remains an exact integer.
+    fn calculate_squared_euclidean_distance(&self, other: &Self) -> u128 {
+        return self
+            .get_coordinates()
+            .iter()
+            .zip(other.get_coordinates().iter())
+            .map(|(a, b)| (a.abs_diff(*b) as u128).pow(2))
+            .sum();
+    }
+}
@@ -1,5 +1,19 @@
 mod cardinaldirection;
+mod compassdirection;
+mod grid;
+mod hexpoint;
+mod metric;
 mod point2d;
+mod point3d;
+mod pointn;
+mod vector2d;
 
 pub use self::cardinaldirection::CardinalDirection;
+pub use self::compassdirection::CompassDirection;
+pub use self::grid::Grid;
+pub use self::hexpoint::HexPoint;
+pub use self::metric::Metric;
 pub use self::point2d::Point2D;
+pub use self::point3d::Point3D;
+pub use self::pointn::PointN;
+pub use self::vector2d::Vector2D;
@@ -1,3 +1,7 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use super::{CompassDirection, Metric, PointN, Vector2D};
+
 /// Represents a single point in two-dimensional Euclidean space.
 #[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
 pub struct Point2D {
@@ -36,32 +40,189 @@ impl Point2D {
 
     /// Moves the point by the specified amount in the x- and y-directions.
     pub fn move_point(&mut self, delta_x: i64, delta_y: i64) {
-        self.x += delta_x;
-        self.y += delta_y;
+        *self += Vector2D::new(delta_x, delta_y);
     }
 
     /// Determines the resulting location if the current location was shifted by given deltas.
     pub fn peek_point(&self, delta_x: i64, delta_y: i64) -> Point2D {
-        return Point2D::new(self.x + delta_x, self.y + delta_y);
+        return *self + Vector2D::new(delta_x, delta_y);
+    }
+
+    /// Determines the location shifted by the given vector, returning None if integer overflow or
+    /// underflow would occur.
+    pub fn checked_add(&self, vector: Vector2D) -> Option<Point2D> {
+        return Some(Point2D::new(
+            self.x.checked_add(vector.get_dx())?,
+            self.y.checked_add(vector.get_dy())?,
+        ));
+    }
+
+    /// Determines the location shifted by the negation of the given vector, returning None if
+    /// integer overflow or underflow would occur.
+    pub fn checked_sub(&self, vector: Vector2D) -> Option<Point2D> {
+        return Some(Point2D::new(
+            self.x.checked_sub(vector.get_dx())?,
+            self.y.checked_sub(vector.get_dy())?,
+        ));
+    }
+
+    /// Determines the vector that would shift the other point onto the current point, returning
+    /// None if integer overflow or underflow would occur.
+    pub fn checked_vector_from(&self, other: &Point2D) -> Option<Vector2D> {
+        return Some(Vector2D::new(self.x.checked_sub(other.x)?, self.y.checked_sub(other.y)?));
     }
 
     /// Gets the eight surrounding points from the current location. Panics if integer overflow or
     /// underflow would occur.
     pub fn get_surrounding_points(&self) -> Vec<Point2D> {
-        let mut output: Vec<Point2D> = vec![];
-        output.push(Point2D::new(self.x, self.y - 1)); // up
-        output.push(Point2D::new(self.x + 1, self.y - 1)); // diag - up right
-        output.push(Point2D::new(self.x + 1, self.y)); // right
-        output.push(Point2D::new(self.x + 1, self.y + 1)); // diag - down right
-        output.push(Point2D::new(self.x, self.y + 1)); // down
-        output.push(Point2D::new(self.x - 1, self.y + 1)); // diag - down left
-        output.push(Point2D::new(self.x - 1, self.y)); // left
-        output.push(Point2D::new(self.x - 1, self.y - 1)); // diag - up left
-        return output;
-    }
-
-    /// Calculates the Manhattan distance between the current point and the other point.
-    pub fn calculate_manhattan_distance(&self, other: &Point2D) -> u64 {
-        return (self.x - other.x).abs() as u64 + (self.y - other.y).abs() as u64;
+        // Compass directions run clockwise from "up", so the neighbours come out in the same order
+        return CompassDirection::all()
+            .iter()
+            .map(|dir| *self + dir.unit_vector())
+            .collect::<Vec<Point2D>>();
+    }
+
+    /// Gets the four points directly above, right of, below and left of the current location.
+    /// Panics if integer overflow or underflow would occur.
+    pub fn get_orthogonal_points(&self) -> Vec<Point2D> {
+        return CompassDirection::all()
+            .iter()
+            .filter(|dir| !dir.is_ordinal())
+            .map(|dir| *self + dir.unit_vector())
+            .collect::<Vec<Point2D>>();
+    }
+
+    /// Determines the minimum and maximum corners of the smallest rectangle containing all of the
+    /// given points. Returns None if no points are given.
+    pub fn get_bounding_box(points: &[Point2D]) -> Option<(Point2D, Point2D)> {
+        let points = points.iter().map(|p| PointN::from(*p)).collect::<Vec<PointN<2>>>();
+        let (min, max) = PointN::get_bounding_box(&points)?;
+        return Some((Point2D::from(min), Point2D::from(max)));
+    }
+}
+
+impl Metric<2> for Point2D {
+    fn get_coordinates(&self) -> [i64; 2] {
+        return [self.x, self.y];
+    }
+}
+
+impl From<PointN<2>> for Point2D {
+    fn from(point: PointN<2>) -> Self {
+        let coords = point.to_array();
+        return Point2D::new(coords[0], coords[1]);
+    }
+}
+
+impl From<Point2D> for PointN<2> {
+    fn from(point: Point2D) -> Self {
+        return PointN::new([point.x, point.y]);
+    }
+}
+
+impl Add<Vector2D> for Point2D {
+    type Output = Point2D;
+
+    fn add(self, vector: Vector2D) -> Point2D {
+        return self.checked_add(vector).expect("Point2D shift overflowed");
+    }
+}
+
+impl AddAssign<Vector2D> for Point2D {
+    fn add_assign(&mut self, vector: Vector2D) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector2D> for Point2D {
+    type Output = Point2D;
+
+    fn sub(self, vector: Vector2D) -> Point2D {
+        return self.checked_sub(vector).expect("Point2D shift overflowed");
+    }
+}
+
+impl SubAssign<Vector2D> for Point2D {
+    fn sub_assign(&mut self, vector: Vector2D) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub<Point2D> for Point2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: Point2D) -> Vector2D {
+        return self.checked_vector_from(&other).expect("Point2D difference overflowed");
+    }
+}
+
+impl From<(i64, i64)> for Point2D {
+    fn from(coords: (i64, i64)) -> Self {
+        return Point2D::new(coords.0, coords.1);
+    }
+}
+
+impl From<Point2D> for (i64, i64) {
+    fn from(point: Point2D) -> Self {
+        return (point.x, point.y);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_vector_arithmetic() {
+        let mut loc = Point2D::new(2, 3);
+        loc += Vector2D::new(0, -1) * 4;
+        assert_eq!(Point2D::new(2, -1), loc);
+        assert_eq!(Point2D::new(1, -1), loc - Vector2D::new(1, 0));
+        assert_eq!(Vector2D::new(2, -4), loc - Point2D::new(0, 3));
+        assert_eq!(Point2D::new(5, 6), Point2D::from((5, 6)));
+        assert_eq!((2, -1), loc.into());
+    }
+
+    #[test]
+    fn test_point_checked_overflow() {
+        let loc = Point2D::new(i64::MAX, i64::MIN);
+        assert_eq!(None, loc.checked_add(Vector2D::new(1, 0)));
+        assert_eq!(None, loc.checked_sub(Vector2D::new(0, 1)));
+        assert_eq!(Some(Point2D::new(i64::MAX - 1, i64::MIN + 1)), loc.checked_add(Vector2D::new(-1, 1)));
+        assert_eq!(None, loc.checked_vector_from(&Point2D::new(-1, 0)));
+    }
+
+    #[test]
+    fn test_surrounding_points_order() {
+        let points = Point2D::new(0, 0).get_surrounding_points();
+        assert_eq!(Point2D::new(0, -1), points[0]);
+        assert_eq!(Point2D::new(1, 1), points[3]);
+        assert_eq!(Point2D::new(-1, -1), points[7]);
+    }
+
+    #[test]
+    fn test_point_metrics_and_bounds() {
+        let a = Point2D::new(-3, 4);
+        let b = Point2D::new(2, 2);
+        assert_eq!(7, a.calculate_manhattan_distance(&b));
+        assert_eq!(5, a.calculate_chebyshev_distance(&b));
+        assert_eq!(29, a.calculate_squared_euclidean_distance(&b));
+        assert_eq!(4, a.get_orthogonal_points().len());
+        assert_eq!(
+            Some((Point2D::new(-3, 2), Point2D::new(2, 4))),
+            Point2D::get_bounding_box(&[a, b])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Point2D shift overflowed")]
+    fn test_surrounding_points_overflow_panics() {
+        Point2D::new(i64::MAX, 0).get_surrounding_points();
+    }
+
+    #[test]
+    #[should_panic(expected = "Point2D shift overflowed")]
+    fn test_surrounding_points_underflow_panics() {
+        Point2D::new(0, i64::MIN).get_surrounding_points();
     }
 }
@@ -0,0 +1,147 @@
+use super::{Metric, PointN};
+
+/// Represents a single point in three-dimensional Euclidean space.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Point3D {
+    x: i64,
+    y: i64,
+    z: i64
+}
+
+impl Point3D {
+    /// Creates a new 3D point.
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self {
+            x: x,
+            y: y,
+            z: z
+        }
+    }
+
+    /// Gets the value of the x-coordinate.
+    pub fn get_x(&self) -> i64 {
+        return self.x;
+    }
+
+    /// Updates the value of the x-coordinate.
+    pub fn set_x(&mut self, x: i64) {
+        self.x = x;
+    }
+
+    /// Gets the value of the y-coordinate.
+    pub fn get_y(&self) -> i64 {
+        return self.y;
+    }
+
+    /// Updates the value of the y-coordinate.
+    pub fn set_y(&mut self, y: i64) {
+        self.y = y;
+    }
+
+    /// Gets the value of the z-coordinate.
+    pub fn get_z(&self) -> i64 {
+        return self.z;
+    }
+
+    /// Updates the value of the z-coordinate.
+    pub fn set_z(&mut self, z: i64) {
+        self.z = z;
+    }
+
+    /// Moves the point by the specified amount in the x-, y- and z-directions. Panics if integer
+    /// overflow or underflow would occur.
+    pub fn move_point(&mut self, delta_x: i64, delta_y: i64, delta_z: i64) {
+        *self = self.peek_point(delta_x, delta_y, delta_z);
+    }
+
+    /// Determines the resulting location if the current location was shifted by given deltas.
+    /// Panics if integer overflow or underflow would occur.
+    pub fn peek_point(&self, delta_x: i64, delta_y: i64, delta_z: i64) -> Point3D {
+        let x = self.x.checked_add(delta_x).expect("Point3D shift overflowed");
+        let y = self.y.checked_add(delta_y).expect("Point3D shift overflowed");
+        let z = self.z.checked_add(delta_z).expect("Point3D shift overflowed");
+        return Point3D::new(x, y, z);
+    }
+
+    /// Gets the six points adjacent to the current location along a single axis. Panics if integer
+    /// overflow or underflow would occur.
+    pub fn get_orthogonal_points(&self) -> Vec<Point3D> {
+        return PointN::from(*self)
+            .get_orthogonal_points()
+            .into_iter()
+            .map(Point3D::from)
+            .collect::<Vec<Point3D>>();
+    }
+
+    /// Gets the 26 points surrounding the current location, including diagonals. Panics if integer
+    /// overflow or underflow would occur.
+    pub fn get_surrounding_points(&self) -> Vec<Point3D> {
+        return PointN::from(*self)
+            .get_surrounding_points()
+            .into_iter()
+            .map(Point3D::from)
+            .collect::<Vec<Point3D>>();
+    }
+
+    /// Determines the minimum and maximum corners of the smallest box containing all of the given
+    /// points. Returns None if no points are given.
+    pub fn get_bounding_box(points: &[Point3D]) -> Option<(Point3D, Point3D)> {
+        let points = points.iter().map(|p| PointN::from(*p)).collect::<Vec<PointN<3>>>();
+        let (min, max) = PointN::get_bounding_box(&points)?;
+        return Some((Point3D::from(min), Point3D::from(max)));
+    }
+}
+
+impl Metric<3> for Point3D {
+    fn get_coordinates(&self) -> [i64; 3] {
+        return [self.x, self.y, self.z];
+    }
+}
+
+impl From<PointN<3>> for Point3D {
+    fn from(point: PointN<3>) -> Self {
+        let coords = point.to_array();
+        return Point3D::new(coords[0], coords[1], coords[2]);
+    }
+}
+
+impl From<Point3D> for PointN<3> {
+    fn from(point: Point3D) -> Self {
+        return PointN::new([point.x, point.y, point.z]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point3d_metrics() {
+        let a = Point3D::new(1, -2, 3);
+        let b = Point3D::new(-3, 4, 3);
+        assert_eq!(10, a.calculate_manhattan_distance(&b));
+        assert_eq!(6, a.calculate_chebyshev_distance(&b));
+        assert_eq!(52, a.calculate_squared_euclidean_distance(&b));
+    }
+
+    #[test]
+    fn test_point3d_neighbours_and_bounds() {
+        let origin = Point3D::new(0, 0, 0);
+        assert_eq!(6, origin.get_orthogonal_points().len());
+        let surrounding = origin.get_surrounding_points();
+        assert_eq!(26, surrounding.len());
+        assert!(!surrounding.contains(&origin));
+        assert_eq!(
+            Some((Point3D::new(-1, -1, -1), Point3D::new(1, 1, 1))),
+            Point3D::get_bounding_box(&surrounding)
+        );
+        assert_eq!(None, Point3D::get_bounding_box(&[]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Point3D shift overflowed")]
+    fn test_point3d_move_point_overflow() {
+        let mut point = Point3D::new(0, 0, i64::MIN);
+        point.move_point(0, 0, -1);
+    }
+}
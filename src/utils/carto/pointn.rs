@@ -0,0 +1,167 @@
+use super::Metric;
+
+/// Represents a single point in N-dimensional Euclidean space.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct PointN<const N: usize> {
+    coords: [i64; N]
+}
+
+impl<const N: usize> PointN<N> {
+    /// Creates a new N-dimensional point.
+    pub fn new(coords: [i64; N]) -> Self {
+        Self {
+            coords: coords
+        }
+    }
+
+    /// Creates a new point located at the origin.
+    pub fn origin() -> Self {
+        return PointN::new([0; N]);
+    }
+
+    /// Gets the value of the co-ordinate along the given axis. Panics if the axis is out of range.
+    pub fn get(&self, axis: usize) -> i64 {
+        return self.coords[axis];
+    }
+
+    /// Updates the value of the co-ordinate along the given axis. Panics if the axis is out of
+    /// range.
+    pub fn set(&mut self, axis: usize, value: i64) {
+        self.coords[axis] = value;
+    }
+
+    /// Gets the co-ordinates of the point as an array.
+    pub fn to_array(&self) -> [i64; N] {
+        return self.coords;
+    }
+
+    /// Determines the resulting location if the current location was shifted by given deltas.
+    /// Panics if integer overflow or underflow would occur.
+    pub fn peek_point(&self, deltas: [i64; N]) -> PointN<N> {
+        let mut coords = self.coords;
+        for axis in 0..N {
+            coords[axis] = coords[axis].checked_add(deltas[axis]).expect("PointN shift overflowed");
+        }
+        return PointN::new(coords);
+    }
+
+    /// Gets the 2N points adjacent to the current location along a single axis, ordered by axis
+    /// with the negative direction first. Panics if integer overflow or underflow would occur.
+    pub fn get_orthogonal_points(&self) -> Vec<PointN<N>> {
+        let mut output: Vec<PointN<N>> = Vec::with_capacity(2 * N);
+        for axis in 0..N {
+            for delta in [-1, 1].iter() {
+                let mut deltas = [0; N];
+                deltas[axis] = *delta;
+                output.push(self.peek_point(deltas));
+            }
+        }
+        return output;
+    }
+
+    /// Gets the 3^N - 1 points surrounding the current location, including all diagonals. Panics if
+    /// integer overflow or underflow would occur.
+    pub fn get_surrounding_points(&self) -> Vec<PointN<N>> {
+        let total = 3usize.pow(N as u32);
+        let mut output: Vec<PointN<N>> = Vec::with_capacity(total - 1);
+        for combo in 0..total {
+            // Each base-3 digit of the combination number selects a delta of -1, 0 or +1
+            let mut deltas = [0; N];
+            let mut remaining = combo;
+            for axis in 0..N {
+                deltas[axis] = (remaining % 3) as i64 - 1;
+                remaining /= 3;
+            }
+            if deltas.iter().all(|d| *d == 0) {
+                continue;
+            }
+            output.push(self.peek_point(deltas));
+        }
+        return output;
+    }
+
+    /// Checks if the point lies within the box bounded (inclusively) by the given corners.
+    pub fn is_within_bounds(&self, min: &PointN<N>, max: &PointN<N>) -> bool {
+        return (0..N).all(|axis| min.coords[axis] <= self.coords[axis] && self.coords[axis] <= max.coords[axis]);
+    }
+
+    /// Determines the minimum and maximum corners of the smallest box containing all of the given
+    /// points. Returns None if no points are given.
+    pub fn get_bounding_box(points: &[PointN<N>]) -> Option<(PointN<N>, PointN<N>)> {
+        let first = points.first()?;
+        let mut min = *first;
+        let mut max = *first;
+        for point in points {
+            for axis in 0..N {
+                min.coords[axis] = min.coords[axis].min(point.coords[axis]);
+                max.coords[axis] = max.coords[axis].max(point.coords[axis]);
+            }
+        }
+        return Some((min, max));
+    }
+}
+
+impl<const N: usize> Metric<N> for PointN<N> {
+    fn get_coordinates(&self) -> [i64; N] {
+        return self.coords;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pointn_metrics() {
+        let a = PointN::new([1, 2, 3, 4]);
+        let b = PointN::new([0, 0, 0, 0]);
+        assert_eq!(10, a.calculate_manhattan_distance(&b));
+        assert_eq!(4, a.calculate_chebyshev_distance(&b));
+        assert_eq!(30, a.calculate_squared_euclidean_distance(&b));
+    }
+
+    #[test]
+    fn test_pointn_metrics_extreme_values() {
+        let a = PointN::new([i64::MIN]);
+        let b = PointN::new([i64::MAX]);
+        assert_eq!(u64::MAX, a.calculate_manhattan_distance(&b));
+        assert_eq!((u64::MAX as u128).pow(2), a.calculate_squared_euclidean_distance(&b));
+        let c = PointN::new([i64::MIN, 0]);
+        let d = PointN::new([i64::MAX, 1]);
+        assert_eq!(None, c.checked_manhattan_distance(&d));
+        assert_eq!(Some(u64::MAX), a.checked_manhattan_distance(&b));
+    }
+
+    #[test]
+    #[should_panic(expected = "Manhattan distance overflowed")]
+    fn test_pointn_manhattan_overflow() {
+        PointN::new([i64::MIN, 0]).calculate_manhattan_distance(&PointN::new([i64::MAX, 1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "PointN shift overflowed")]
+    fn test_pointn_peek_point_overflow() {
+        PointN::new([0, i64::MAX]).peek_point([0, 1]);
+    }
+
+    #[test]
+    fn test_pointn_neighbours() {
+        let origin = PointN::<4>::origin();
+        assert_eq!(8, origin.get_orthogonal_points().len());
+        let surrounding = origin.get_surrounding_points();
+        assert_eq!(80, surrounding.len());
+        for point in surrounding.iter() {
+            assert_eq!(1, point.calculate_chebyshev_distance(&origin));
+        }
+    }
+
+    #[test]
+    fn test_pointn_bounding_box() {
+        let points = vec![PointN::new([3, -1]), PointN::new([-2, 5]), PointN::new([0, 0])];
+        let (min, max) = PointN::get_bounding_box(&points).unwrap();
+        assert_eq!(PointN::new([-2, -1]), min);
+        assert_eq!(PointN::new([3, 5]), max);
+        assert!(points.iter().all(|p| p.is_within_bounds(&min, &max)));
+        assert!(!PointN::new([4, 0]).is_within_bounds(&min, &max));
+    }
+}
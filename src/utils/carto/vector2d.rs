@@ -0,0 +1,137 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Represents a displacement in two-dimensional Euclidean space. Arithmetic on vectors panics if
+/// integer overflow or underflow would occur, regardless of the build profile.
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug, Default)]
+pub struct Vector2D {
+    dx: i64,
+    dy: i64
+}
+
+impl Vector2D {
+    /// Creates a new 2D vector.
+    pub fn new(dx: i64, dy: i64) -> Self {
+        Self {
+            dx: dx,
+            dy: dy
+        }
+    }
+
+    /// Gets the x-component of the vector.
+    pub fn get_dx(&self) -> i64 {
+        return self.dx;
+    }
+
+    /// Gets the y-component of the vector.
+    pub fn get_dy(&self) -> i64 {
+        return self.dy;
+    }
+
+    /// Adds the other vector, returning None if integer overflow or underflow would occur.
+    pub fn checked_add(&self, other: Vector2D) -> Option<Vector2D> {
+        return Some(Vector2D::new(self.dx.checked_add(other.dx)?, self.dy.checked_add(other.dy)?));
+    }
+
+    /// Subtracts the other vector, returning None if integer overflow or underflow would occur.
+    pub fn checked_sub(&self, other: Vector2D) -> Option<Vector2D> {
+        return Some(Vector2D::new(self.dx.checked_sub(other.dx)?, self.dy.checked_sub(other.dy)?));
+    }
+
+    /// Scales the vector by the given factor, returning None if integer overflow or underflow
+    /// would occur.
+    pub fn checked_mul(&self, factor: i64) -> Option<Vector2D> {
+        return Some(Vector2D::new(self.dx.checked_mul(factor)?, self.dy.checked_mul(factor)?));
+    }
+
+    /// Negates the vector, returning None if integer overflow would occur.
+    pub fn checked_neg(&self) -> Option<Vector2D> {
+        return Some(Vector2D::new(self.dx.checked_neg()?, self.dy.checked_neg()?));
+    }
+}
+
+impl Add for Vector2D {
+    type Output = Vector2D;
+
+    fn add(self, other: Vector2D) -> Vector2D {
+        return self.checked_add(other).expect("Vector2D addition overflowed");
+    }
+}
+
+impl AddAssign for Vector2D {
+    fn add_assign(&mut self, other: Vector2D) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vector2D {
+    type Output = Vector2D;
+
+    fn sub(self, other: Vector2D) -> Vector2D {
+        return self.checked_sub(other).expect("Vector2D subtraction overflowed");
+    }
+}
+
+impl SubAssign for Vector2D {
+    fn sub_assign(&mut self, other: Vector2D) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<i64> for Vector2D {
+    type Output = Vector2D;
+
+    fn mul(self, factor: i64) -> Vector2D {
+        return self.checked_mul(factor).expect("Vector2D scaling overflowed");
+    }
+}
+
+impl Neg for Vector2D {
+    type Output = Vector2D;
+
+    fn neg(self) -> Vector2D {
+        return self.checked_neg().expect("Vector2D negation overflowed");
+    }
+}
+
+impl From<(i64, i64)> for Vector2D {
+    fn from(components: (i64, i64)) -> Self {
+        return Vector2D::new(components.0, components.1);
+    }
+}
+
+impl From<Vector2D> for (i64, i64) {
+    fn from(vector: Vector2D) -> Self {
+        return (vector.dx, vector.dy);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_arithmetic() {
+        let mut vec = Vector2D::new(1, -2) + Vector2D::new(3, 4);
+        assert_eq!(Vector2D::new(4, 2), vec);
+        vec += Vector2D::new(0, 1) * 5;
+        assert_eq!(Vector2D::new(4, 7), vec);
+        assert_eq!(Vector2D::new(-4, -7), -vec);
+        assert_eq!(Vector2D::new(3, 6), vec - Vector2D::new(1, 1));
+        assert_eq!((4, 7), vec.into());
+        assert_eq!(vec, Vector2D::from((4, 7)));
+    }
+
+    #[test]
+    fn test_vector_checked_overflow() {
+        assert_eq!(None, Vector2D::new(i64::MAX, 0).checked_add(Vector2D::new(1, 0)));
+        assert_eq!(None, Vector2D::new(0, i64::MIN).checked_sub(Vector2D::new(0, 1)));
+        assert_eq!(None, Vector2D::new(i64::MAX / 2 + 1, 0).checked_mul(2));
+        assert_eq!(None, Vector2D::new(i64::MIN, 0).checked_neg());
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector2D scaling overflowed")]
+    fn test_vector_mul_overflow_panics() {
+        let _ = Vector2D::new(0, i64::MIN) * -1;
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+/// Around 500 of the most common general English words, one per line, used by
+/// `Dictionary::common_english`.
+const COMMON_ENGLISH_WORDS: &str = include_str!("english_words.txt");
+
+/// Shifts a single letter through the alphabet by the given amount, wrapping around at either end.
+/// Positive shifts move forward (towards 'z') and negative shifts move backward. Case is preserved
+/// and any other character is returned unchanged.
+pub fn shift_letter(c: char, shift: i64) -> char {
+    let base = match c {
+        'a'..='z' => b'a',
+        'A'..='Z' => b'A',
+        _ => return c,
+    };
+    let offset = (c as u8 - base) as i64;
+    return (base + (offset + shift.rem_euclid(26)).rem_euclid(26) as u8) as char;
+}
+
+/// Shifts every letter in the text by the given amount (see `shift_letter`).
+pub fn shift_text(text: &str, shift: i64) -> String {
+    // Reduce the shift first so it cannot overflow when added to a letter offset
+    let shift = shift.rem_euclid(26);
+    return text.chars().map(|c| shift_letter(c, shift)).collect::<String>();
+}
+
+/// Encrypts the plaintext with a Caesar cipher, shifting each letter forward by the key.
+pub fn encrypt(plaintext: &str, key: u64) -> String {
+    return shift_text(plaintext, (key % 26) as i64);
+}
+
+/// Decrypts the ciphertext from a Caesar cipher, shifting each letter backward by the key.
+pub fn decrypt(ciphertext: &str, key: u64) -> String {
+    return shift_text(ciphertext, -((key % 26) as i64));
+}
+
+/// Represents a set of known words, used to score how plausible a candidate plaintext is. Words are
+/// stored in lowercase.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dictionary {
+    words: HashSet<String>
+}
+
+impl Dictionary {
+    /// Creates a new dictionary containing the given words.
+    pub fn new<'a, I: IntoIterator<Item = &'a str>>(words: I) -> Self {
+        Self {
+            words: words.into_iter().map(|word| word.to_ascii_lowercase()).collect::<HashSet<String>>()
+        }
+    }
+
+    /// Creates a dictionary of common general English words from the embedded word list.
+    pub fn common_english() -> Self {
+        return Dictionary::new(COMMON_ENGLISH_WORDS.lines());
+    }
+
+    /// Checks if the dictionary contains the given word, compared case-insensitively.
+    pub fn contains(&self, word: &str) -> bool {
+        return self.words.contains(&word.to_ascii_lowercase());
+    }
+}
+
+/// Represents one candidate decryption found when cracking a Caesar cipher.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CrackCandidate {
+    key: u64,
+    plaintext: String,
+    score: u64
+}
+
+impl CrackCandidate {
+    /// Gets the key that decrypts the ciphertext into the candidate plaintext.
+    pub fn get_key(&self) -> u64 {
+        return self.key;
+    }
+
+    /// Gets the candidate plaintext.
+    pub fn get_plaintext(&self) -> &str {
+        return &self.plaintext;
+    }
+
+    /// Gets the English score of the plaintext (see `score_english`).
+    pub fn get_score(&self) -> u64 {
+        return self.score;
+    }
+}
+
+/// Scores how English the text looks - the total number of letters in words found in the given
+/// dictionary. Words are separated by any non-letter characters and compared case-insensitively.
+pub fn score_english(text: &str, dictionary: &Dictionary) -> u64 {
+    return text
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| dictionary.contains(word))
+        .map(|word| word.len() as u64)
+        .sum();
+}
+
+/// Brute-forces a Caesar cipher by decrypting the ciphertext with all 26 keys, scoring each
+/// candidate against the given dictionary. Candidates are ordered from most to least plausible by
+/// English score, with ties broken by smallest key.
+pub fn crack(ciphertext: &str, dictionary: &Dictionary) -> Vec<CrackCandidate> {
+    let mut candidates = (0..26)
+        .map(|key| {
+            let plaintext = decrypt(ciphertext, key);
+            let score = score_english(&plaintext, dictionary);
+            CrackCandidate {
+                key: key,
+                plaintext: plaintext,
+                score: score
+            }
+        })
+        .collect::<Vec<CrackCandidate>>();
+    candidates.sort_by(|a, b| b.score.cmp(&a.score).then(a.key.cmp(&b.key)));
+    return candidates;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_letter() {
+        assert_eq!('b', shift_letter('a', 1));
+        assert_eq!('a', shift_letter('z', 1));
+        assert_eq!('Z', shift_letter('A', -1));
+        assert_eq!('-', shift_letter('-', 5));
+        assert_eq!('v', shift_letter('q', 343));
+        assert_eq!('i', shift_letter('q', i64::MIN));
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let plaintext = "Very Encrypted Name!";
+        for key in [0, 1, 13, 25, 26, 343, u64::MAX] {
+            assert_eq!(plaintext, decrypt(&encrypt(plaintext, key), key));
+        }
+        assert_eq!("very encrypted name", encrypt("qzmt zixmtkozy ivhz", 343));
+    }
+
+    #[test]
+    fn test_score_english() {
+        let dictionary = Dictionary::common_english();
+        assert!(dictionary.contains("Water"));
+        assert!(!dictionary.contains("northpole"));
+        assert_eq!(12, score_english("The NORTH-road, qzmt", &dictionary));
+        let custom = Dictionary::new(vec!["Bunny", "egg"]);
+        assert_eq!(8, score_english("bunny egg the", &custom));
+    }
+
+    #[test]
+    fn test_crack() {
+        let dictionary = Dictionary::common_english();
+        let ciphertext = encrypt("meet me at the north gate when the sun goes down", 17);
+        let candidates = crack(&ciphertext, &dictionary);
+        assert_eq!(26, candidates.len());
+        assert_eq!(17, candidates[0].get_key());
+        assert_eq!("meet me at the north gate when the sun goes down", candidates[0].get_plaintext());
+        assert!(candidates[0].get_score() > candidates[1].get_score());
+    }
+}
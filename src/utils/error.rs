@@ -0,0 +1,80 @@
+use std::error::Error;
+use std::fmt;
+
+/// Represents the ways in which parsing puzzle input or solving a puzzle can fail.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AocError {
+    /// Raw input could not be parsed. Line and column numbers are one-based, and the text is the
+    /// offending portion of the input.
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    /// Input was parsed successfully but the puzzle has no solution for it.
+    Unsolvable(String),
+    /// A parameter given to a solver or utility is outside of its accepted range.
+    InvalidParameter(String),
+    /// Input could not be read from its source.
+    Io(String),
+}
+
+impl AocError {
+    /// Creates a parse error for the given token, which starts at the given byte offset within the
+    /// input line. The column is calculated by counting the characters before the offset.
+    pub fn parse_token(line_index: usize, line: &str, offset: usize, token: &str, reason: &str) -> AocError {
+        let column = line.get(..offset).map_or_else(|| line.chars().count(), |prefix| prefix.chars().count()) + 1;
+        return AocError::Parse {
+            line: line_index + 1,
+            column: column,
+            text: token.to_string(),
+            reason: reason.to_string(),
+        };
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                text,
+                reason,
+            } => write!(f, "parse error at line {}, column {} ({:?}): {}", line, column, text, reason),
+            AocError::Unsolvable(reason) => write!(f, "unsolvable input: {}", reason),
+            AocError::InvalidParameter(reason) => write!(f, "invalid parameter: {}", reason),
+            AocError::Io(reason) => write!(f, "failed to read input: {}", reason),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_position() {
+        let line = "  12 3x4 56";
+        let error = AocError::parse_token(4, line, 5, "3x4", "not a number");
+        assert_eq!(
+            AocError::Parse {
+                line: 5,
+                column: 6,
+                text: String::from("3x4"),
+                reason: String::from("not a number"),
+            },
+            error
+        );
+        assert_eq!(
+            "parse error at line 5, column 6 (\"3x4\"): not a number",
+            error.to_string()
+        );
+        // Columns count characters rather than bytes
+        let error = AocError::parse_token(0, "é x", 3, "x", "bad token");
+        assert!(matches!(error, AocError::Parse { line: 1, column: 3, .. }));
+    }
+}
@@ -0,0 +1,131 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Represents the ways ties between equally frequent items can be broken when ranking.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TieBreak {
+    /// Smaller items (e.g. earlier letters of the alphabet) rank first.
+    Alphabetical,
+    /// Larger items (e.g. later letters of the alphabet) rank first.
+    ReverseAlphabetical,
+    /// Items that were first counted earlier rank first.
+    FirstOccurrence,
+}
+
+/// Counts how often each item occurs, keeping track of the order in which items were first seen so
+/// ties can be broken consistently.
+#[derive(Clone, Debug)]
+pub struct FrequencyCounter<T> {
+    counts: HashMap<T, u64>,
+    first_seen: HashMap<T, usize>
+}
+
+impl<T: Copy + Eq + Hash + Ord> FrequencyCounter<T> {
+    /// Creates a new counter with no items counted.
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+            first_seen: HashMap::new()
+        }
+    }
+
+    /// Creates a new counter with every item from the iterator counted.
+    pub fn from_items<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut counter = FrequencyCounter::new();
+        for item in items {
+            counter.add(item);
+        }
+        return counter;
+    }
+
+    /// Counts one more occurrence of the item.
+    pub fn add(&mut self, item: T) {
+        let next_index = self.first_seen.len();
+        self.first_seen.entry(item).or_insert(next_index);
+        *self.counts.entry(item).or_insert(0) += 1;
+    }
+
+    /// Gets the number of times the item has been counted.
+    pub fn get_count(&self, item: &T) -> u64 {
+        return self.counts.get(item).copied().unwrap_or(0);
+    }
+
+    /// Gets the number of different items counted.
+    pub fn get_distinct_count(&self) -> usize {
+        return self.counts.len();
+    }
+
+    /// Ranks every counted item with its count, from most to least frequent, with ties broken as
+    /// given.
+    pub fn rank(&self, tie_break: TieBreak) -> Vec<(T, u64)> {
+        let mut ranking = self.counts.iter().map(|(item, count)| (*item, *count)).collect::<Vec<(T, u64)>>();
+        ranking.sort_by(|a, b| b.1.cmp(&a.1).then(self.compare_ties(&a.0, &b.0, tie_break)));
+        return ranking;
+    }
+
+    /// Gets up to k of the most frequent items, in rank order with ties broken as given.
+    pub fn top_k(&self, k: usize, tie_break: TieBreak) -> Vec<T> {
+        return self.rank(tie_break).into_iter().take(k).map(|(item, _)| item).collect::<Vec<T>>();
+    }
+
+    /// Gets the most frequent item, with ties broken as given. Returns None if nothing is counted.
+    pub fn most_common(&self, tie_break: TieBreak) -> Option<T> {
+        return self
+            .counts
+            .iter()
+            .min_by(|a, b| b.1.cmp(a.1).then(self.compare_ties(a.0, b.0, tie_break)))
+            .map(|(item, _)| *item);
+    }
+
+    /// Gets the least frequent item, with ties broken as given. Returns None if nothing is counted.
+    pub fn least_common(&self, tie_break: TieBreak) -> Option<T> {
+        return self
+            .counts
+            .iter()
+            .min_by(|a, b| a.1.cmp(b.1).then(self.compare_ties(a.0, b.0, tie_break)))
+            .map(|(item, _)| *item);
+    }
+
+    /// Compares two equally frequent items, with the item that should rank first ordered first.
+    fn compare_ties(&self, a: &T, b: &T, tie_break: TieBreak) -> Ordering {
+        match tie_break {
+            TieBreak::Alphabetical => return a.cmp(b),
+            TieBreak::ReverseAlphabetical => return b.cmp(a),
+            TieBreak::FirstOccurrence => return self.first_seen[a].cmp(&self.first_seen[b]),
+        }
+    }
+}
+
+impl<T: Copy + Eq + Hash + Ord> Default for FrequencyCounter<T> {
+    fn default() -> Self {
+        return FrequencyCounter::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_and_top_k() {
+        let counter = FrequencyCounter::from_items("notarealroom".chars());
+        assert_eq!(3, counter.get_count(&'o'));
+        assert_eq!(0, counter.get_count(&'z'));
+        assert_eq!(8, counter.get_distinct_count());
+        assert_eq!(vec!['o', 'a', 'r', 'e', 'l'], counter.top_k(5, TieBreak::Alphabetical));
+        assert_eq!(vec!['o', 'r', 'a', 't', 'n'], counter.top_k(5, TieBreak::ReverseAlphabetical));
+        assert_eq!(vec!['o', 'a', 'r', 'n', 't'], counter.top_k(5, TieBreak::FirstOccurrence));
+        assert_eq!(('o', 3), counter.rank(TieBreak::Alphabetical)[0]);
+    }
+
+    #[test]
+    fn test_most_and_least_common() {
+        let counter = FrequencyCounter::from_items(vec![3, 1, 2, 2, 1, 4]);
+        assert_eq!(Some(1), counter.most_common(TieBreak::Alphabetical));
+        assert_eq!(Some(2), counter.most_common(TieBreak::ReverseAlphabetical));
+        assert_eq!(Some(3), counter.least_common(TieBreak::Alphabetical));
+        assert_eq!(Some(4), counter.least_common(TieBreak::ReverseAlphabetical));
+        assert_eq!(None, FrequencyCounter::<char>::new().most_common(TieBreak::FirstOccurrence));
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::thread;
+
+/// Number of consecutive indexes hashed by a worker thread in one chunk of a search round.
+const CHUNK_SIZE: u64 = 20_000;
+
+/// Predicate used to select the digests yielded by a hash miner.
+pub type HashPredicate = Arc<dyn Fn(&md5::Digest) -> bool + Send + Sync>;
+
+/// Checks if the hexadecimal form of the digest starts with the given number of zeroes, without
+/// formatting it.
+pub fn has_leading_zero_nibbles(digest: &md5::Digest, zero_nibbles: usize) -> bool {
+    if zero_nibbles > 32 {
+        return false;
+    }
+    if digest[..zero_nibbles / 2].iter().any(|byte| *byte != 0) {
+        return false;
+    }
+    return zero_nibbles.is_multiple_of(2) || digest[zero_nibbles / 2] & 0xF0 == 0;
+}
+
+/// Gets the value of the hexadecimal digit at the given position (0 to 31) of the digest.
+pub fn get_hex_digit(digest: &md5::Digest, position: usize) -> u8 {
+    let byte = digest[position / 2];
+    if position.is_multiple_of(2) {
+        return byte >> 4;
+    }
+    return byte & 0x0F;
+}
+
+/// Iterator over the MD5 digests of a salt followed by each index (in decimal) from a starting
+/// index upwards, yielding (index, digest) for every digest accepted by the predicate, in index
+/// order. Indexes are hashed in rounds, with each round split into chunks searched in parallel
+/// across the available threads. Hits from each round are merged in chunk order, so results are
+/// deterministic regardless of thread count.
+pub struct HashMiner {
+    salt_context: md5::Context,
+    predicate: HashPredicate,
+    threads: u64,
+    round_start: u64,
+    resume_index: u64,
+    pending: VecDeque<(u64, md5::Digest)>
+}
+
+impl HashMiner {
+    /// Creates a miner yielding digests with hexadecimal forms starting with the given number of
+    /// zeroes.
+    pub fn with_leading_zeroes(salt: &str, zero_nibbles: usize) -> Self {
+        return HashMiner::with_predicate(salt, Arc::new(move |digest| has_leading_zero_nibbles(digest, zero_nibbles)));
+    }
+
+    /// Creates a miner yielding digests accepted by the given predicate.
+    pub fn with_predicate(salt: &str, predicate: HashPredicate) -> Self {
+        // Hash the salt once, then clone the context for every index
+        let mut salt_context = md5::Context::new();
+        salt_context.consume(salt.as_bytes());
+        Self {
+            salt_context: salt_context,
+            predicate: predicate,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()) as u64,
+            round_start: 0,
+            resume_index: 0,
+            pending: VecDeque::new()
+        }
+    }
+
+    /// Moves the search to start from the given index, discarding any hits already found.
+    pub fn starting_at(mut self, index: u64) -> Self {
+        self.round_start = index;
+        self.resume_index = index;
+        self.pending.clear();
+        return self;
+    }
+
+    /// Gets the index following the last hit yielded (or the starting index if none have been).
+    /// A new miner started from this index continues where this one left off.
+    pub fn get_resume_index(&self) -> u64 {
+        return self.resume_index;
+    }
+
+    /// Searches the next round of indexes, queueing up any hits found.
+    fn search_round(&mut self) {
+        let round_start = self.round_start;
+        let chunk_hits = thread::scope(|scope| {
+            let workers = (0..self.threads)
+                .map(|i| {
+                    let chunk_start = round_start.saturating_add(i * CHUNK_SIZE);
+                    let chunk_end = chunk_start.saturating_add(CHUNK_SIZE);
+                    let salt_context = &self.salt_context;
+                    let predicate = &self.predicate;
+                    scope.spawn(move || {
+                        let mut hits: Vec<(u64, md5::Digest)> = vec![];
+                        for index in chunk_start..chunk_end {
+                            let mut context = salt_context.clone();
+                            context.consume(index.to_string().as_bytes());
+                            let digest = context.compute();
+                            if predicate(&digest) {
+                                hits.push((index, digest));
+                            }
+                        }
+                        hits
+                    })
+                })
+                .collect::<Vec<thread::ScopedJoinHandle<Vec<(u64, md5::Digest)>>>>();
+            return workers.into_iter().map(|worker| worker.join().unwrap()).collect::<Vec<Vec<(u64, md5::Digest)>>>();
+        });
+        self.pending.extend(chunk_hits.into_iter().flatten());
+        self.round_start = round_start.saturating_add(self.threads * CHUNK_SIZE);
+    }
+}
+
+impl Iterator for HashMiner {
+    type Item = (u64, md5::Digest);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Indexes run out at the top of the u64 range
+        while self.pending.is_empty() {
+            if self.round_start == u64::MAX {
+                return None;
+            }
+            self.search_round();
+        }
+        let (index, digest) = self.pending.pop_front().unwrap();
+        self.resume_index = index + 1;
+        return Some((index, digest));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Weak predicate so hits are common in tests.
+    fn is_weak_hit(digest: &md5::Digest) -> bool {
+        return digest[0] == 0 && digest[1] < 0x40;
+    }
+
+    #[test]
+    fn test_leading_zero_nibbles() {
+        let digest = md5::Digest([0, 0, 0x0F, 0xFF, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(has_leading_zero_nibbles(&digest, 0));
+        assert!(has_leading_zero_nibbles(&digest, 5));
+        assert!(!has_leading_zero_nibbles(&digest, 6));
+        assert!(!has_leading_zero_nibbles(&digest, 33));
+        assert!(has_leading_zero_nibbles(&md5::Digest([0; 16]), 32));
+        assert_eq!(0xF, get_hex_digit(&digest, 5));
+        assert_eq!(0, get_hex_digit(&digest, 4));
+    }
+
+    #[test]
+    fn test_miner_matches_sequential_search() {
+        let hits = HashMiner::with_predicate("abc", Arc::new(is_weak_hit)).take(50).collect::<Vec<(u64, md5::Digest)>>();
+        let expected = (0..)
+            .map(|index: u64| (index, md5::compute(format!("abc{}", index))))
+            .filter(|(_, digest)| is_weak_hit(digest))
+            .take(50)
+            .collect::<Vec<(u64, md5::Digest)>>();
+        assert_eq!(expected, hits);
+        let zero_hits = HashMiner::with_leading_zeroes("abc", 3).take(5).collect::<Vec<(u64, md5::Digest)>>();
+        assert!(zero_hits.iter().all(|(_, digest)| format!("{:x}", digest).starts_with("000")));
+    }
+
+    #[test]
+    fn test_miner_resume() {
+        let mut miner = HashMiner::with_predicate("abc", Arc::new(is_weak_hit));
+        let first = miner.by_ref().take(10).collect::<Vec<(u64, md5::Digest)>>();
+        assert_eq!(first[9].0 + 1, miner.get_resume_index());
+        let resumed = HashMiner::with_predicate("abc", Arc::new(is_weak_hit))
+            .starting_at(miner.get_resume_index())
+            .take(10)
+            .collect::<Vec<(u64, md5::Digest)>>();
+        assert_eq!(miner.take(10).collect::<Vec<(u64, md5::Digest)>>(), resumed);
+        assert_eq!(None, HashMiner::with_leading_zeroes("abc", 1).starting_at(u64::MAX).next());
+    }
+}
@@ -1 +1,7 @@
 pub mod carto;
+pub mod cipher;
+pub mod error;
+pub mod frequency;
+pub mod hashmine;
+pub mod rng;
+pub mod search;
@@ -0,0 +1,59 @@
+/// Xorshift pseudo-random number generator. Fast and deterministic for a given seed, which makes it
+/// suitable for reproducible test data and visual effects, but not for anything security related.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct XorShift {
+    state: u64
+}
+
+impl XorShift {
+    /// Creates a new generator with the given seed. A seed of zero (which would only ever generate
+    /// zeroes) is replaced with one.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed.max(1)
+        }
+    }
+
+    /// Generates the next pseudo-random value across the full u64 range.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        return self.state;
+    }
+
+    /// Generates the next pseudo-random value from zero (inclusive) to the limit (exclusive). The
+    /// distribution is very slightly biased unless the limit is a power of two. Panics if the limit
+    /// is zero.
+    pub fn next_below(&mut self, limit: u64) -> u64 {
+        return self.next_u64() % limit;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xorshift_deterministic() {
+        let mut a = XorShift::new(0x2016);
+        let mut b = XorShift::new(0x2016);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_eq!(XorShift::new(1), XorShift::new(0));
+        assert_ne!(0, XorShift::new(0).next_u64());
+    }
+
+    #[test]
+    fn test_xorshift_next_below() {
+        let mut rng = XorShift::new(7);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            let value = rng.next_below(6);
+            assert!(value < 6);
+            seen[value as usize] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+}
@@ -0,0 +1,268 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Represents the outcome of a successful search - the total cost of reaching the goal state and
+/// the path of states taken from the start state (inclusive) to the goal state (inclusive).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SearchResult<S> {
+    cost: u64,
+    path: Vec<S>
+}
+
+impl<S> SearchResult<S> {
+    /// Gets the total cost of the path from the start state to the goal state.
+    pub fn get_cost(&self) -> u64 {
+        return self.cost;
+    }
+
+    /// Gets the states visited from the start state to the goal state, inclusive.
+    pub fn get_path(&self) -> &Vec<S> {
+        return &self.path;
+    }
+
+    /// Consumes the result, returning the path of states from start to goal.
+    pub fn into_path(self) -> Vec<S> {
+        return self.path;
+    }
+
+    /// Gets the goal state reached by the search.
+    pub fn get_goal(&self) -> &S {
+        return self.path.last().unwrap();
+    }
+}
+
+/// Conducts a breadth-first search from the start state, treating every move as costing one step.
+/// The successor function returns the states reachable in one step from the given state. Returns
+/// None if no goal state is reachable.
+pub fn breadth_first_search<S, FN, I, FG>(
+    start: S,
+    mut successors: FN,
+    mut is_goal: FG,
+) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    // Each node records its state, the index of its parent node and the steps taken to reach it
+    let mut nodes: Vec<(S, Option<usize>, u64)> = vec![(start.clone(), None, 0)];
+    let mut seen: HashSet<S> = HashSet::new();
+    seen.insert(start);
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    queue.push_back(0);
+    while let Some(index) = queue.pop_front() {
+        let (state, _, steps) = nodes[index].clone();
+        if is_goal(&state) {
+            return Some(reconstruct_path(&nodes, index, steps));
+        }
+        for next in successors(&state) {
+            if !seen.insert(next.clone()) {
+                continue;
+            }
+            nodes.push((next, Some(index), steps + 1));
+            queue.push_back(nodes.len() - 1);
+        }
+    }
+    return None;
+}
+
+/// Conducts a Dijkstra search from the start state. The successor function returns each state
+/// reachable in one move from the given state, along with the cost of that move. Returns None if
+/// no goal state is reachable.
+pub fn dijkstra_search<S, FN, I, FG>(
+    start: S,
+    successors: FN,
+    is_goal: FG,
+) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    FG: FnMut(&S) -> bool,
+{
+    return a_star_search(start, successors, |_| 0, is_goal);
+}
+
+/// Conducts an A* search from the start state. The successor function returns each state reachable
+/// in one move from the given state, along with the cost of that move. The heuristic must never
+/// overestimate the remaining cost to a goal state, otherwise the path found may not be the
+/// cheapest. Returns None if no goal state is reachable.
+pub fn a_star_search<S, FN, I, FH, FG>(
+    start: S,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<SearchResult<S>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    FH: FnMut(&S) -> u64,
+    FG: FnMut(&S) -> bool,
+{
+    let mut nodes: Vec<(S, Option<usize>, u64)> = vec![(start.clone(), None, 0)];
+    let mut best_costs: HashMap<S, u64> = HashMap::new();
+    // Min-heap ordered by estimated total cost, then by cost so far, then by node index
+    let mut frontier: BinaryHeap<Reverse<(u64, u64, usize)>> = BinaryHeap::new();
+    frontier.push(Reverse((heuristic(&start), 0, 0)));
+    best_costs.insert(start, 0);
+    while let Some(Reverse((_, cost, index))) = frontier.pop() {
+        let state = nodes[index].0.clone();
+        // Skip stale entries superseded by a cheaper route to the same state
+        if cost > *best_costs.get(&state).unwrap() {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(reconstruct_path(&nodes, index, cost));
+        }
+        for (next, move_cost) in successors(&state) {
+            let next_cost = cost + move_cost;
+            if let Some(&known_cost) = best_costs.get(&next) {
+                if known_cost <= next_cost {
+                    continue;
+                }
+            }
+            let estimate = next_cost + heuristic(&next);
+            best_costs.insert(next.clone(), next_cost);
+            nodes.push((next, Some(index), next_cost));
+            frontier.push(Reverse((estimate, next_cost, nodes.len() - 1)));
+        }
+    }
+    return None;
+}
+
+/// Finds every state reachable from the start state within the given number of steps, treating
+/// every move as costing one step. Returns a map of each reachable state (including the start
+/// state) to the minimum number of steps required to reach it.
+pub fn find_reachable_within<S, FN, I>(start: S, max_steps: u64, mut successors: FN) -> HashMap<S, u64>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached: HashMap<S, u64> = HashMap::new();
+    let mut queue: VecDeque<(S, u64)> = VecDeque::new();
+    reached.insert(start.clone(), 0);
+    queue.push_back((start, 0));
+    while let Some((state, steps)) = queue.pop_front() {
+        if steps == max_steps {
+            continue;
+        }
+        for next in successors(&state) {
+            if reached.contains_key(&next) {
+                continue;
+            }
+            reached.insert(next.clone(), steps + 1);
+            queue.push_back((next, steps + 1));
+        }
+    }
+    return reached;
+}
+
+/// Rebuilds the path ending at the given node by following parent links back to the start node.
+fn reconstruct_path<S: Clone>(nodes: &[(S, Option<usize>, u64)], end: usize, cost: u64) -> SearchResult<S> {
+    let mut path: Vec<S> = vec![];
+    let mut cursor = Some(end);
+    while let Some(index) = cursor {
+        path.push(nodes[index].0.clone());
+        cursor = nodes[index].1;
+    }
+    path.reverse();
+    return SearchResult {
+        cost: cost,
+        path: path
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::carto::{Grid, Metric, Point2D};
+
+    /// Maze with walls ('#') used to test searches over Point2D states.
+    const MAZE: &str = "\
+        ..#....\n\
+        .##.##.\n\
+        ...#...\n\
+        .#...#.\n";
+
+    fn get_open_neighbours(maze: &Grid<char>, loc: &Point2D) -> Vec<Point2D> {
+        return maze
+            .get_orthogonal_neighbours(loc)
+            .into_iter()
+            .filter(|p| maze[*p] == '.')
+            .collect::<Vec<Point2D>>();
+    }
+
+    #[test]
+    fn test_bfs_over_grid() {
+        let maze = Grid::from_text(MAZE).unwrap();
+        let goal = Point2D::new(6, 0);
+        let result = breadth_first_search(
+            Point2D::new(0, 0),
+            |loc| get_open_neighbours(&maze, loc),
+            |loc| *loc == goal,
+        )
+        .unwrap();
+        assert_eq!(12, result.get_cost());
+        assert_eq!(13, result.get_path().len());
+        assert_eq!(Point2D::new(0, 0), result.get_path()[0]);
+        assert_eq!(&goal, result.get_goal());
+        // Every step in the path must be a single orthogonal move
+        for pair in result.get_path().windows(2) {
+            assert_eq!(1, pair[0].calculate_manhattan_distance(&pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let maze = Grid::from_text(MAZE).unwrap();
+        let goal = Point2D::new(6, 3);
+        let successors = |loc: &Point2D| {
+            get_open_neighbours(&maze, loc)
+                .into_iter()
+                .map(|p| (p, 1))
+                .collect::<Vec<(Point2D, u64)>>()
+        };
+        let dijkstra = dijkstra_search(Point2D::new(0, 0), successors, |loc| *loc == goal).unwrap();
+        let a_star = a_star_search(
+            Point2D::new(0, 0),
+            successors,
+            |loc| loc.calculate_manhattan_distance(&goal),
+            |loc| *loc == goal,
+        )
+        .unwrap();
+        assert_eq!(dijkstra.get_cost(), a_star.get_cost());
+        assert_eq!(11, a_star.get_cost());
+    }
+
+    #[test]
+    fn test_dijkstra_weighted_states() {
+        // Reach 10 from 1 where doubling costs 1 and incrementing costs 3
+        let result = dijkstra_search(
+            1u64,
+            |n| vec![(n * 2, 1), (n + 1, 3)].into_iter().filter(|(m, _)| *m <= 20),
+            |n| *n == 10,
+        )
+        .unwrap();
+        assert_eq!(&vec![1, 2, 4, 5, 10], result.get_path());
+        assert_eq!(6, result.get_cost());
+    }
+
+    #[test]
+    fn test_search_unreachable() {
+        let result = breadth_first_search(0u64, |n| vec![(n + 2) % 10], |n| *n == 5);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_reachable_within() {
+        let maze = Grid::from_text(MAZE).unwrap();
+        let reached = find_reachable_within(Point2D::new(0, 0), 3, |loc| get_open_neighbours(&maze, loc));
+        assert_eq!(6, reached.len());
+        assert_eq!(Some(&3), reached.get(&Point2D::new(1, 2)));
+        assert_eq!(None, reached.get(&Point2D::new(2, 2)));
+    }
+}
@@ -0,0 +1,300 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use super::solution::Puzzle;
+use super::utils::error::AocError;
+
+/// Represents the expected answers for one day's puzzle run against one input file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AnswerEntry {
+    day: u32,
+    input_path: PathBuf,
+    expected: [Option<String>; 2]
+}
+
+impl AnswerEntry {
+    /// Creates a new answer entry. Parts without a known answer are None.
+    pub fn new(day: u32, input_path: PathBuf, part_1: Option<String>, part_2: Option<String>) -> Self {
+        Self {
+            day: day,
+            input_path: input_path,
+            expected: [part_1, part_2]
+        }
+    }
+
+    /// Gets the day number of the puzzle.
+    pub fn get_day(&self) -> u32 {
+        return self.day;
+    }
+
+    /// Gets the path of the input file.
+    pub fn get_input_path(&self) -> &Path {
+        return &self.input_path;
+    }
+
+    /// Gets the expected answer for the given part (1 or 2), if known. Returns None for any other
+    /// part.
+    pub fn get_expected(&self, part: u32) -> Option<&String> {
+        return self.expected.get((part as usize).checked_sub(1)?)?.as_ref();
+    }
+}
+
+/// Represents the result of checking a single day and part against its expected answer.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum VerifyStatus {
+    /// Solver produced the expected answer.
+    Pass,
+    /// Solver produced an answer different to the expected answer.
+    Fail { expected: String, actual: String },
+    /// No check could be made - the reason describes what is missing.
+    Missing(String),
+    /// Input could not be read or the solver returned an error.
+    Error(String),
+}
+
+/// Represents the outcome of verifying one part of a day's puzzle against one input file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct VerifyOutcome {
+    day: u32,
+    part: u32,
+    input_path: Option<PathBuf>,
+    status: VerifyStatus,
+    duration: Duration
+}
+
+impl VerifyOutcome {
+    /// Gets the day number of the puzzle.
+    pub fn get_day(&self) -> u32 {
+        return self.day;
+    }
+
+    /// Gets the part of the puzzle (1 or 2).
+    pub fn get_part(&self) -> u32 {
+        return self.part;
+    }
+
+    /// Gets the path of the input file checked, or None if the day has no entry in the answers.
+    pub fn get_input_path(&self) -> Option<&Path> {
+        return self.input_path.as_deref();
+    }
+
+    /// Gets the status of the check.
+    pub fn get_status(&self) -> &VerifyStatus {
+        return &self.status;
+    }
+
+    /// Gets the time taken to parse the input and solve the part.
+    pub fn get_duration(&self) -> Duration {
+        return self.duration;
+    }
+}
+
+/// Loads the answer entries from the JSON answers file at the given path. Input file paths within
+/// the answers file are resolved relative to the directory containing the answers file.
+///
+/// The answers file holds a single object with an "answers" array, with each element having the
+/// fields "day" (number), "input" (string) and optionally "part1" and "part2" (strings).
+pub fn load_answers(answers_path: &Path) -> Result<Vec<AnswerEntry>, AocError> {
+    let raw_answers = match fs::read_to_string(answers_path) {
+        Ok(raw_answers) => raw_answers,
+        Err(e) => {
+            let message = format!("cannot read answers file {}: {}", answers_path.display(), e);
+            return Err(AocError::InvalidParameter(message));
+        }
+    };
+    let base_dir = answers_path.parent().unwrap_or_else(|| Path::new(""));
+    return parse_answers(&raw_answers, base_dir);
+}
+
+/// Parses answer entries from the contents of a JSON answers file. Input file paths are resolved
+/// relative to the given base directory.
+pub fn parse_answers(raw_answers: &str, base_dir: &Path) -> Result<Vec<AnswerEntry>, AocError> {
+    let root: Value = match serde_json::from_str(raw_answers) {
+        Ok(root) => root,
+        Err(e) => {
+            return Err(AocError::Parse {
+                line: e.line(),
+                column: e.column(),
+                text: raw_answers.lines().nth(e.line().saturating_sub(1)).unwrap_or("").to_string(),
+                reason: e.to_string(),
+            });
+        }
+    };
+    let entries = match root.get("answers").and_then(|answers| answers.as_array()) {
+        Some(entries) => entries,
+        None => return Err(answers_error(&root, "expected an object with an \"answers\" array")),
+    };
+    let mut answers: Vec<AnswerEntry> = vec![];
+    for entry in entries {
+        let day = match entry.get("day").and_then(|day| day.as_u64()) {
+            Some(day) if (1..=25).contains(&day) => day as u32,
+            _ => return Err(answers_error(entry, "expected \"day\" to be a number from 1 to 25")),
+        };
+        let input = match entry.get("input").and_then(|input| input.as_str()) {
+            Some(input) => input,
+            None => return Err(answers_error(entry, "expected \"input\" to be a string")),
+        };
+        let mut expected: Vec<Option<String>> = vec![];
+        for field in ["part1", "part2"].iter() {
+            match entry.get(*field) {
+                None | Some(Value::Null) => expected.push(None),
+                Some(Value::String(answer)) => expected.push(Some(answer.to_string())),
+                Some(_) => {
+                    return Err(answers_error(entry, &format!("expected \"{}\" to be a string", field)));
+                }
+            }
+        }
+        let part_2 = expected.pop().unwrap();
+        let part_1 = expected.pop().unwrap();
+        answers.push(AnswerEntry::new(day, base_dir.join(input), part_1, part_2));
+    }
+    return Ok(answers);
+}
+
+/// Creates an error for a structurally invalid element of the answers file. The JSON value has no
+/// position information, so the element itself is included in the message instead.
+fn answers_error(value: &Value, reason: &str) -> AocError {
+    return AocError::InvalidParameter(format!("answers file element {}: {}", value, reason));
+}
+
+/// Runs every part of every registered puzzle against each of its input files from the answers,
+/// comparing the results with the expected answers. Registered parts without an answer entry are
+/// reported as missing, as are answer entries for days without a registered puzzle.
+pub fn verify_all(registry: &BTreeMap<u32, Box<dyn Puzzle>>, answers: &[AnswerEntry]) -> Vec<VerifyOutcome> {
+    let mut outcomes: Vec<VerifyOutcome> = vec![];
+    // Answer entries for days that are not registered cannot be checked
+    for entry in answers.iter().filter(|entry| !registry.contains_key(&entry.day)) {
+        for part in 1..=2 {
+            outcomes.push(VerifyOutcome {
+                day: entry.day,
+                part: part,
+                input_path: Some(entry.input_path.clone()),
+                status: VerifyStatus::Missing(String::from("day is not implemented")),
+                duration: Duration::default(),
+            });
+        }
+    }
+    for (day, puzzle) in registry.iter() {
+        let day_entries = answers.iter().filter(|entry| entry.day == *day).collect::<Vec<&AnswerEntry>>();
+        if day_entries.is_empty() {
+            for part in (1..=2).filter(|part| puzzle.has_part(*part)) {
+                outcomes.push(VerifyOutcome {
+                    day: *day,
+                    part: part,
+                    input_path: None,
+                    status: VerifyStatus::Missing(String::from("no answers entry for day")),
+                    duration: Duration::default(),
+                });
+            }
+            continue;
+        }
+        for entry in day_entries {
+            outcomes.extend(verify_entry(puzzle.as_ref(), entry));
+        }
+    }
+    outcomes.sort_by_key(|outcome| (outcome.day, outcome.part));
+    return outcomes;
+}
+
+/// Runs both parts of the puzzle against the input file of the answer entry.
+fn verify_entry(puzzle: &dyn Puzzle, entry: &AnswerEntry) -> Vec<VerifyOutcome> {
+    let raw_input = fs::read_to_string(&entry.input_path);
+    let mut outcomes: Vec<VerifyOutcome> = vec![];
+    for part in 1..=2 {
+        let start = Instant::now();
+        let status = if !puzzle.has_part(part) {
+            VerifyStatus::Missing(String::from("part is not implemented"))
+        } else {
+            match &raw_input {
+                Err(e) => VerifyStatus::Error(format!("cannot read input: {}", e)),
+                Ok(raw_input) => match (puzzle.run_part(part, raw_input), entry.get_expected(part)) {
+                    (Err(e), _) => VerifyStatus::Error(e.to_string()),
+                    (Ok(actual), None) => VerifyStatus::Missing(format!("no expected answer (got {:?})", actual)),
+                    (Ok(actual), Some(expected)) if actual == *expected => VerifyStatus::Pass,
+                    (Ok(actual), Some(expected)) => VerifyStatus::Fail {
+                        expected: expected.to_string(),
+                        actual: actual,
+                    },
+                },
+            }
+        };
+        outcomes.push(VerifyOutcome {
+            day: entry.day,
+            part: part,
+            input_path: Some(entry.input_path.clone()),
+            status: status,
+            duration: start.elapsed(),
+        });
+    }
+    return outcomes;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::registry;
+    use std::env;
+
+    #[test]
+    fn test_parse_answers() {
+        let raw_answers = r#"{"answers": [{"day": 1, "input": "a.txt", "part1": "5"}]}"#;
+        let answers = parse_answers(raw_answers, Path::new("inputs")).unwrap();
+        assert_eq!(
+            vec![AnswerEntry::new(1, PathBuf::from("inputs/a.txt"), Some(String::from("5")), None)],
+            answers
+        );
+        assert_eq!(Some(&String::from("5")), answers[0].get_expected(1));
+        assert_eq!(None, answers[0].get_expected(2));
+        assert_eq!(None, answers[0].get_expected(0));
+        assert_eq!(None, answers[0].get_expected(3));
+        assert_eq!(
+            Err(AocError::InvalidParameter(String::from(
+                "answers file element {\"day\":26,\"input\":\"a.txt\"}: expected \"day\" to be a number from 1 to 25"
+            ))),
+            parse_answers(r#"{"answers": [{"day": 26, "input": "a.txt"}]}"#, Path::new(""))
+        );
+        assert!(matches!(
+            parse_answers("{\n\"answers\": [,]}", Path::new("")),
+            Err(AocError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_all() {
+        let input_dir = env::temp_dir().join(format!("aoc2016_verify_{}", std::process::id()));
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("day1.txt"), "R8, R4, R4, R8").unwrap();
+        let answers = vec![
+            AnswerEntry::new(1, input_dir.join("day1.txt"), Some(String::from("8")), Some(String::from("5"))),
+            AnswerEntry::new(2, input_dir.join("missing.txt"), None, None),
+            AnswerEntry::new(25, input_dir.join("day25.txt"), None, None),
+        ];
+        let outcomes = verify_all(&registry(), &answers);
+        fs::remove_dir_all(&input_dir).unwrap();
+        let find_status = |day: u32, part: u32| {
+            return outcomes
+                .iter()
+                .find(|outcome| outcome.get_day() == day && outcome.get_part() == part)
+                .unwrap()
+                .get_status()
+                .clone();
+        };
+        assert_eq!(VerifyStatus::Pass, find_status(1, 1));
+        assert_eq!(
+            VerifyStatus::Fail {
+                expected: String::from("5"),
+                actual: String::from("4"),
+            },
+            find_status(1, 2)
+        );
+        assert!(matches!(find_status(2, 1), VerifyStatus::Error(_)));
+        assert!(matches!(find_status(3, 1), VerifyStatus::Missing(_)));
+        assert!(matches!(find_status(25, 1), VerifyStatus::Missing(_)));
+        // Day 9 part 2 is not offered by its solution, so has no outcome without an answers entry
+        assert!(!outcomes.iter().any(|outcome| outcome.get_day() == 9 && outcome.get_part() == 2));
+    }
+}